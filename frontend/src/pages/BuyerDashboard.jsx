@@ -7,6 +7,7 @@ import Navbar from '../components/Navbar'
 import Loading from '../components/Loading'
 import LandCard from '../components/LandCard'
 import { getAllLands, getUserFractionalLands, getAllRequests, getLand, getLandOwner, makePayment } from '../utils/contractInteraction'
+import { getRequestStatus } from '../utils/helpers'
 import { Home, ShoppingBag, Wallet as WalletIcon, User, Clock, CheckCircle, XCircle, DollarSign } from 'lucide-react'
 
 const BuyerDashboard = () => {
@@ -369,9 +370,9 @@ const MyRequests = () => {
   }
 
   const filteredRequests = requests.filter(req => {
-    if (filter === 'pending') return !req.approved && !req.payment_received
-    if (filter === 'approved') return req.approved && !req.payment_received
-    if (filter === 'completed') return req.payment_received
+    if (filter === 'pending') return getRequestStatus(req) === 'Pending'
+    if (filter === 'approved') return getRequestStatus(req) === 'Approved'
+    if (filter === 'completed') return ['Paid', 'Completed'].includes(getRequestStatus(req))
     return true
   })
 
@@ -472,12 +473,17 @@ const MyRequests = () => {
                 <div className="flex flex-col justify-between items-end gap-4">
                   {/* Status Badge */}
                   <div>
-                    {request.payment_received ? (
+                    {['Paid', 'Completed'].includes(getRequestStatus(request)) ? (
                       <span className="badge badge-success text-lg px-4 py-2">
                         <CheckCircle className="w-4 h-4 mr-2" />
                         Completed
                       </span>
-                    ) : request.approved ? (
+                    ) : ['Rejected', 'Cancelled', 'Expired'].includes(getRequestStatus(request)) ? (
+                      <span className="badge badge-error text-lg px-4 py-2">
+                        <XCircle className="w-4 h-4 mr-2" />
+                        {getRequestStatus(request)}
+                      </span>
+                    ) : getRequestStatus(request) === 'Approved' ? (
                       <span className="badge badge-info text-lg px-4 py-2">
                         <CheckCircle className="w-4 h-4 mr-2" />
                         Approved - Awaiting Payment
@@ -491,7 +497,7 @@ const MyRequests = () => {
                   </div>
 
                   {/* Payment Button */}
-                  {request.approved && !request.payment_received && (
+                  {getRequestStatus(request) === 'Approved' && (
                     <button
                       onClick={() => handlePayment(request.req_id)}
                       className="btn btn-primary"
@@ -14,7 +14,8 @@ import {
   rejectSeller,
   rejectBuyer,
   isLandInspector,
-  initializeContract
+  initializeContract,
+  addInspector
 } from '../utils/contractInteraction'
 import { toast } from 'react-toastify'
 import { Users, Home, CheckCircle, XCircle, Clock, User, Settings } from 'lucide-react'
@@ -165,10 +166,13 @@ const InspectorDashboard = () => {
 const ContractSetup = ({ onComplete }) => {
   const { publicKey } = useStellar()
   const [formData, setFormData] = useState({
+    paymentToken: import.meta.env.VITE_PAYMENT_TOKEN_ID || '',
     inspectorAddress: 'GDMORAOEBRU43PT4L4FLIJQGJTHZTXKCYDBIVKN676XY3AI7VLHJMJWR',
     name: '',
     age: '',
-    designation: ''
+    designation: '',
+    state: '',
+    city: '*'
   })
   const [submitting, setSubmitting] = useState(false)
 
@@ -177,12 +181,15 @@ const ContractSetup = ({ onComplete }) => {
     setSubmitting(true)
 
     try {
-      await initializeContract(
+      // The connected wallet becomes the admin, then registers the first inspector
+      await initializeContract(publicKey, formData.paymentToken)
+      await addInspector(
         publicKey,
         formData.inspectorAddress,
         formData.name,
         parseInt(formData.age),
-        formData.designation
+        formData.designation,
+        [{ state: formData.state, city: formData.city }]
       )
       toast.success('Contract initialized successfully!')
       onComplete()
@@ -205,6 +212,20 @@ const ContractSetup = ({ onComplete }) => {
       </h4>
 
       <form onSubmit={handleSubmit} className="space-y-4">
+        <div>
+          <label className="block text-sm font-medium text-gray-400 mb-2">
+            Payment Token Contract
+          </label>
+          <input
+            type="text"
+            value={formData.paymentToken}
+            onChange={(e) => setFormData({ ...formData, paymentToken: e.target.value })}
+            className="input w-full"
+            placeholder="e.g., C... (token contract used for land payments)"
+            required
+          />
+        </div>
+
         <div>
           <label className="block text-sm font-medium text-gray-400 mb-2">
             Inspector Wallet Address
@@ -268,6 +289,36 @@ const ContractSetup = ({ onComplete }) => {
           />
         </div>
 
+        <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
+          <div>
+            <label className="block text-sm font-medium text-gray-400 mb-2">
+              Jurisdiction State
+            </label>
+            <input
+              type="text"
+              value={formData.state}
+              onChange={(e) => setFormData({ ...formData, state: e.target.value })}
+              className="input w-full"
+              placeholder="e.g., Maharashtra"
+              required
+            />
+          </div>
+
+          <div>
+            <label className="block text-sm font-medium text-gray-400 mb-2">
+              Jurisdiction City
+            </label>
+            <input
+              type="text"
+              value={formData.city}
+              onChange={(e) => setFormData({ ...formData, city: e.target.value })}
+              className="input w-full"
+              placeholder="* for the whole state"
+              required
+            />
+          </div>
+        </div>
+
         <div className="flex gap-4">
           <button
             type="submit"
@@ -287,7 +338,7 @@ const ContractSetup = ({ onComplete }) => {
 
         <div className="p-4 rounded-lg bg-blue-500/10 border border-blue-500/30">
           <p className="text-sm text-blue-400">
-            <strong>Note:</strong> This action can only be performed once. The inspector address cannot be changed after initialization.
+            <strong>Note:</strong> Initialization can only be performed once and makes the connected wallet the admin. The admin can add more inspectors later.
           </p>
         </div>
       </form>
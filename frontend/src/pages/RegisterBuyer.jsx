@@ -17,6 +17,7 @@ const RegisterBuyer = () => {
     name: '',
     age: '',
     city: '',
+    state: '',
     aadharNumber: '',
     panNumber: '',
     email: '',
@@ -82,6 +83,10 @@ const RegisterBuyer = () => {
       newErrors.city = 'City is required'
     }
 
+    if (!formData.state.trim()) {
+      newErrors.state = 'State is required'
+    }
+
     if (!isValidAadhar(formData.aadharNumber)) {
       newErrors.aadharNumber = 'Invalid Aadhar number (12 digits required)'
     }
@@ -132,6 +137,7 @@ const RegisterBuyer = () => {
         formData.name,
         parseInt(formData.age),
         formData.city,
+        formData.state,
         formData.aadharNumber,
         formData.panNumber.toUpperCase(),
         documentHash,
@@ -254,6 +260,25 @@ const RegisterBuyer = () => {
                   <p className="mt-1 text-sm text-red-400">{errors.city}</p>
                 )}
               </div>
+
+              {/* State */}
+              <div>
+                <label htmlFor="state" className="block text-sm font-medium text-gray-300 mb-2">
+                  State *
+                </label>
+                <input
+                  type="text"
+                  id="state"
+                  name="state"
+                  value={formData.state}
+                  onChange={handleInputChange}
+                  className={`input ${errors.state ? 'input-error' : ''}`}
+                  placeholder="Enter your state"
+                />
+                {errors.state && (
+                  <p className="mt-1 text-sm text-red-400">{errors.state}</p>
+                )}
+              </div>
             </div>
 
             {/* Email */}
@@ -15,6 +15,8 @@ const RegisterSeller = () => {
   const [formData, setFormData] = useState({
     name: '',
     age: '',
+    city: '',
+    state: '',
     aadharNumber: '',
     panNumber: '',
     landsOwned: '',
@@ -74,6 +76,14 @@ const RegisterSeller = () => {
       newErrors.age = 'Age must be between 18 and 100'
     }
 
+    if (!formData.city.trim()) {
+      newErrors.city = 'City is required'
+    }
+
+    if (!formData.state.trim()) {
+      newErrors.state = 'State is required'
+    }
+
     if (!isValidAadhar(formData.aadharNumber)) {
       newErrors.aadharNumber = 'Invalid Aadhar number (12 digits required)'
     }
@@ -113,6 +123,8 @@ const RegisterSeller = () => {
         publicKey,
         formData.name,
         parseInt(formData.age),
+        formData.city,
+        formData.state,
         formData.aadharNumber,
         formData.panNumber.toUpperCase(),
         formData.landsOwned,
@@ -210,6 +222,44 @@ const RegisterSeller = () => {
                 <p className="mt-1 text-sm text-red-400">{errors.age}</p>
               )}
             </div>
+
+            <div className="grid grid-cols-1 md:grid-cols-2 gap-6">
+              <div>
+                <label htmlFor="city" className="block text-sm font-medium text-gray-300 mb-2">
+                  City *
+                </label>
+                <input
+                  type="text"
+                  id="city"
+                  name="city"
+                  value={formData.city}
+                  onChange={handleInputChange}
+                  className={`input ${errors.city ? 'input-error' : ''}`}
+                  placeholder="Enter your city"
+                />
+                {errors.city && (
+                  <p className="mt-1 text-sm text-red-400">{errors.city}</p>
+                )}
+              </div>
+
+              <div>
+                <label htmlFor="state" className="block text-sm font-medium text-gray-300 mb-2">
+                  State *
+                </label>
+                <input
+                  type="text"
+                  id="state"
+                  name="state"
+                  value={formData.state}
+                  onChange={handleInputChange}
+                  className={`input ${errors.state ? 'input-error' : ''}`}
+                  placeholder="Enter your state"
+                />
+                {errors.state && (
+                  <p className="mt-1 text-sm text-red-400">{errors.state}</p>
+                )}
+              </div>
+            </div>
           </div>
 
           {/* Identity Documents */}
@@ -234,6 +234,19 @@ const toScVal = {
     }
     return StellarSdk.nativeToScVal(value, { type: innerType })
   },
+  // Vec<Jurisdiction>; struct fields are encoded as a map with sorted symbol keys
+  jurisdictions: (values) => StellarSdk.xdr.ScVal.scvVec(
+    values.map(({ state, city }) => StellarSdk.xdr.ScVal.scvMap([
+      new StellarSdk.xdr.ScMapEntry({
+        key: StellarSdk.xdr.ScVal.scvSymbol('city'),
+        val: StellarSdk.nativeToScVal(city, { type: 'string' }),
+      }),
+      new StellarSdk.xdr.ScMapEntry({
+        key: StellarSdk.xdr.ScVal.scvSymbol('state'),
+        val: StellarSdk.nativeToScVal(state, { type: 'string' }),
+      }),
+    ]))
+  ),
 }
 
 /**
@@ -242,23 +255,51 @@ const toScVal = {
 
 // ==================== INITIALIZATION ====================
 
-export const initializeContract = async (publicKey, inspectorAddress, name, age, designation) => {
+export const initializeContract = async (adminAddress, paymentTokenAddress) => {
   try {
     const contract = new StellarSdk.Contract(CONTRACT_ID)
     
     const operation = contract.call(
       'initialize',
+      toScVal.address(adminAddress),
+      toScVal.address(paymentTokenAddress)
+    )
+
+    const result = await buildAndSubmitTransaction(adminAddress, operation)
+    toast.success('Contract initialized successfully!')
+    return result
+  } catch (error) {
+    toast.error(`Initialization failed: ${error.message}`)
+    throw error
+  }
+}
+
+export const addInspector = async (
+  adminAddress,
+  inspectorAddress,
+  name,
+  age,
+  designation,
+  jurisdictions
+) => {
+  try {
+    const contract = new StellarSdk.Contract(CONTRACT_ID)
+    
+    const operation = contract.call(
+      'add_inspector',
+      toScVal.address(adminAddress),
       toScVal.address(inspectorAddress),
       toScVal.string(name),
       toScVal.u32(age),
-      toScVal.string(designation)
+      toScVal.string(designation),
+      toScVal.jurisdictions(jurisdictions)
     )
 
-    const result = await buildAndSubmitTransaction(publicKey, operation)
-    toast.success('Contract initialized successfully!')
+    const result = await buildAndSubmitTransaction(adminAddress, operation)
+    toast.success('Inspector added!')
     return result
   } catch (error) {
-    toast.error(`Initialization failed: ${error.message}`)
+    toast.error(`Adding inspector failed: ${error.message}`)
     throw error
   }
 }
@@ -278,6 +319,8 @@ export const registerSeller = async (
   publicKey,
   name,
   age,
+  city,
+  state,
   aadharNumber,
   panNumber,
   landsOwned,
@@ -291,6 +334,8 @@ export const registerSeller = async (
       toScVal.address(publicKey),
       toScVal.string(name),
       toScVal.u32(age),
+      toScVal.string(city),
+      toScVal.string(state),
       toScVal.string(aadharNumber),
       toScVal.string(panNumber),
       toScVal.string(landsOwned),
@@ -310,6 +355,8 @@ export const updateSeller = async (
   publicKey,
   name,
   age,
+  city,
+  state,
   aadharNumber,
   panNumber,
   landsOwned
@@ -322,6 +369,8 @@ export const updateSeller = async (
       toScVal.address(publicKey),
       toScVal.string(name),
       toScVal.u32(age),
+      toScVal.string(city),
+      toScVal.string(state),
       toScVal.string(aadharNumber),
       toScVal.string(panNumber),
       toScVal.string(landsOwned)
@@ -352,6 +401,7 @@ export const registerBuyer = async (
   name,
   age,
   city,
+  state,
   aadharNumber,
   panNumber,
   document,
@@ -366,6 +416,7 @@ export const registerBuyer = async (
       toScVal.string(name),
       toScVal.u32(age),
       toScVal.string(city),
+      toScVal.string(state),
       toScVal.string(aadharNumber),
       toScVal.string(panNumber),
       toScVal.string(document),
@@ -386,6 +437,7 @@ export const updateBuyer = async (
   name,
   age,
   city,
+  state,
   aadharNumber,
   panNumber,
   email
@@ -399,6 +451,7 @@ export const updateBuyer = async (
       toScVal.string(name),
       toScVal.u32(age),
       toScVal.string(city),
+      toScVal.string(state),
       toScVal.string(aadharNumber),
       toScVal.string(panNumber),
       toScVal.string(email)
@@ -624,14 +677,13 @@ export const isLandVerified = async (landId) => {
 
 // ==================== REQUEST FUNCTIONS ====================
 
-export const requestLand = async (buyerAddress, sellerAddress, landId) => {
+export const requestLand = async (buyerAddress, landId) => {
   try {
     const contract = new StellarSdk.Contract(CONTRACT_ID)
     
     const operation = contract.call(
       'request_land',
       toScVal.address(buyerAddress),
-      toScVal.address(sellerAddress),
       toScVal.u32(landId)
     )
 
@@ -644,15 +696,15 @@ export const requestLand = async (buyerAddress, sellerAddress, landId) => {
   }
 }
 
-export const requestFractionalLand = async (buyerAddress, sellerAddress, landId) => {
+export const requestFractionalLand = async (buyerAddress, landId, numFractions = 1) => {
   try {
     const contract = new StellarSdk.Contract(CONTRACT_ID)
     
     const operation = contract.call(
       'request_fractional_land',
       toScVal.address(buyerAddress),
-      toScVal.address(sellerAddress),
-      toScVal.u32(landId)
+      toScVal.u32(landId),
+      toScVal.u32(numFractions)
     )
 
     const result = await buildAndSubmitTransaction(buyerAddress, operation)
@@ -764,15 +816,14 @@ export const getAvailableFractions = async (landId) => {
 
 // ==================== OWNERSHIP TRANSFER ====================
 
-export const transferOwnership = async (inspectorAddress, landId, newOwnerAddress) => {
+export const transferOwnership = async (inspectorAddress, requestId) => {
   try {
     const contract = new StellarSdk.Contract(CONTRACT_ID)
     
     const operation = contract.call(
       'transfer_ownership',
       toScVal.address(inspectorAddress),
-      toScVal.u32(landId),
-      toScVal.address(newOwnerAddress)
+      toScVal.u32(requestId)
     )
 
     const result = await buildAndSubmitTransaction(inspectorAddress, operation)
@@ -896,6 +947,7 @@ export const getAllSellers = async () => {
 export default {
   // Initialization
   initializeContract,
+  addInspector,
   isLandInspector,
   
   // Seller
@@ -40,7 +40,6 @@ const CONTRACT_ERRORS = {
   NOT_INSPECTOR: 'Only Land Inspector can perform this action',
   INVALID_FRACTIONS: 'Invalid number of fractions (must be 1-100)',
   ALL_FRACTIONS_SOLD: 'All fractions have been sold',
-  ALREADY_OWNS_FRACTION: 'Buyer already owns a fraction of this land',
   NOT_FRACTIONAL_LAND: 'This is not fractional land',
   IS_FRACTIONAL_LAND: 'This is fractional land',
   CANNOT_TRANSFER_FRACTIONAL: 'Cannot transfer ownership of fractional land',
@@ -54,6 +53,73 @@ const CONTRACT_ERRORS = {
   REQUEST_NOT_FOUND: 'Request not found',
 }
 
+/**
+ * RegistryError codes returned by the contract as `Error(Contract, #code)`.
+ * Must stay in sync with `RegistryError` in land-registry-contract/src/lib.rs
+ */
+const REGISTRY_ERROR_CODES = {
+  1: CONTRACT_ERRORS.ALREADY_INITIALIZED,
+  2: 'Contract not initialized',
+  3: CONTRACT_ERRORS.NOT_INSPECTOR,
+  4: 'Only the registry admin can perform this action',
+  5: 'Land Inspector not found',
+  6: 'Land Inspector already exists',
+  7: 'Outside the Land Inspector\'s jurisdiction',
+  8: 'Contract data is not at this version yet: a migration is unfinished or the data is newer',
+  9: 'This operation is temporarily paused by the registry admin',
+  10: CONTRACT_ERRORS.ALREADY_REGISTERED,
+  11: CONTRACT_ERRORS.SELLER_NOT_FOUND,
+  12: 'Seller not verified',
+  13: CONTRACT_ERRORS.BUYER_NOT_FOUND,
+  14: 'Buyer not verified',
+  15: 'Address is not a registered buyer or seller',
+  20: CONTRACT_ERRORS.LAND_NOT_FOUND,
+  21: 'Land owner not found',
+  22: CONTRACT_ERRORS.IS_FRACTIONAL_LAND,
+  23: CONTRACT_ERRORS.NOT_FRACTIONAL_LAND,
+  24: CONTRACT_ERRORS.INVALID_FRACTIONS,
+  25: CONTRACT_ERRORS.ALL_FRACTIONS_SOLD,
+  27: 'Fractional ownership not found',
+  29: CONTRACT_ERRORS.CANNOT_TRANSFER_FRACTIONAL,
+  30: 'A land with this property PID is already registered',
+  31: 'A land with this survey number is already registered in this city',
+  32: 'Land has not been verified by an inspector',
+  33: 'The request seller no longer owns this land',
+  34: 'You do not own this fraction',
+  35: 'This fraction is not listed for sale',
+  36: 'Invalid price: it must be positive, and at least one token per fraction',
+  37: 'Only the land owner can do this',
+  38: 'No pending fractionalization for this land',
+  39: 'This land is not listed for sale',
+  40: CONTRACT_ERRORS.REQUEST_NOT_FOUND,
+  41: CONTRACT_ERRORS.NOT_SELLER,
+  42: CONTRACT_ERRORS.NOT_BUYER,
+  43: CONTRACT_ERRORS.REQUEST_NOT_APPROVED,
+  44: CONTRACT_ERRORS.PAYMENT_ALREADY_RECEIVED,
+  45: 'Payment not received',
+  46: 'Request is no longer open',
+  47: 'Request has expired',
+  48: 'Request has not expired yet',
+  50: 'Escrow not found',
+  51: 'Escrow already settled',
+  52: 'Escrow deadline not reached',
+  53: 'Funds for this land are still held in escrow',
+  60: 'No pending role transfer',
+  61: 'Only the proposed address can accept this transfer',
+  70: 'Amount must be greater than zero',
+  71: 'There is no income to claim',
+  80: 'Proposal not found',
+  81: 'This proposal is no longer active',
+  82: 'Voting on this proposal has closed',
+  83: 'Voting on this proposal has not ended yet',
+  84: 'You hold no fractions that can vote on this',
+  85: 'Invalid governance settings',
+  86: 'A buyout needs an approved parcel sale or a supermajority of the fractions',
+  87: 'Approved fraction requests are still awaiting payment',
+  88: 'This land already has the maximum number of proposals open',
+  89: 'The acquirer cannot vote on its own buyout',
+}
+
 /**
  * Parse Stellar error
  */
@@ -114,7 +180,7 @@ export const parseStellarError = (error) => {
 }
 
 /**
- * Parse contract-specific error from panic message
+ * Parse contract-specific error from error code or panic message
  */
 export const parseContractError = (error) => {
   if (!error) return 'Contract error occurred'
@@ -122,6 +188,15 @@ export const parseContractError = (error) => {
   const errorString = error.toString().toLowerCase()
   const errorMessage = error.message?.toLowerCase() || ''
 
+  // Typed contract errors surface as `Error(Contract, #code)` in simulation results
+  const codeMatch = (error.message || error.toString()).match(/Error\(Contract, #(\d+)\)/)
+  if (codeMatch) {
+    const code = parseInt(codeMatch[1], 10)
+    if (REGISTRY_ERROR_CODES[code]) {
+      return REGISTRY_ERROR_CODES[code]
+    }
+  }
+
   // Check each contract error
   for (const [key, message] of Object.entries(CONTRACT_ERRORS)) {
     const searchTerm = message.toLowerCase()
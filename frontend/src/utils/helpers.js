@@ -157,6 +157,15 @@ export const getStatusColor = (status) => {
   return colors[status?.toLowerCase()] || 'badge-info'
 }
 
+/**
+ * Get a LandRequest status name ('Pending', 'Approved', 'Paid', ...).
+ * Contract enums decode as a single-element array, e.g. ['Approved'].
+ */
+export const getRequestStatus = (request) => {
+  const status = request?.status
+  return Array.isArray(status) ? status[0] : status
+}
+
 /**
  * Format land area
  */
@@ -0,0 +1,525 @@
+// Contract events published on every registry state transition.
+// Topics are fixed per event (e.g. ["land", "added"]) followed by the #[topic] fields,
+// so indexers can filter on ids/addresses without re-reading storage.
+use soroban_sdk::{contractevent, Address, BytesN, String, Vec};
+
+use crate::{PauseScope, ProposalAction};
+
+#[contractevent(topics = ["registry", "initialized"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Initialized {
+    #[topic]
+    pub admin: Address,
+    pub payment_token: Address,
+}
+
+#[contractevent(topics = ["escrow", "period_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowPeriodSet {
+    #[topic]
+    pub admin: Address,
+    pub period: u64,
+}
+
+#[contractevent(topics = ["registry", "upgraded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+#[contractevent(topics = ["registry", "migrated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+// Inspector roster
+
+#[contractevent(topics = ["inspector", "added"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InspectorAdded {
+    #[topic]
+    pub inspector: Address,
+    pub admin: Address,
+}
+
+#[contractevent(topics = ["inspector", "removed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InspectorRemoved {
+    #[topic]
+    pub inspector: Address,
+    pub admin: Address,
+}
+
+#[contractevent(topics = ["inspector", "jurisdiction_assigned"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurisdictionAssigned {
+    #[topic]
+    pub inspector: Address,
+    pub state: String,
+    pub city: String,
+}
+
+#[contractevent(topics = ["inspector", "jurisdiction_revoked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurisdictionRevoked {
+    #[topic]
+    pub inspector: Address,
+    pub state: String,
+    pub city: String,
+}
+
+#[contractevent(topics = ["registry", "paused"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paused {
+    #[topic]
+    pub admin: Address,
+    pub scopes: Vec<PauseScope>,
+}
+
+#[contractevent(topics = ["registry", "unpaused"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unpaused {
+    #[topic]
+    pub admin: Address,
+    pub scopes: Vec<PauseScope>,
+}
+
+// Key rotation
+
+#[contractevent(topics = ["admin", "transfer_proposed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferProposed {
+    #[topic]
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+#[contractevent(topics = ["admin", "transferred"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    #[topic]
+    pub new_admin: Address,
+    pub previous_admin: Address,
+}
+
+#[contractevent(topics = ["admin", "transfer_cancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferCancelled {
+    #[topic]
+    pub admin: Address,
+}
+
+#[contractevent(topics = ["inspector", "transfer_proposed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InspectorTransferProposed {
+    #[topic]
+    pub inspector: Address,
+    pub new_inspector: Address,
+    pub proposed_by: Address,
+}
+
+#[contractevent(topics = ["inspector", "transferred"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InspectorTransferred {
+    #[topic]
+    pub inspector: Address,
+    pub new_inspector: Address,
+}
+
+#[contractevent(topics = ["inspector", "transfer_cancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InspectorTransferCancelled {
+    #[topic]
+    pub inspector: Address,
+    pub cancelled_by: Address,
+}
+
+// Registration
+
+#[contractevent(topics = ["seller", "registered"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellerRegistered {
+    #[topic]
+    pub seller: Address,
+}
+
+#[contractevent(topics = ["seller", "updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellerUpdated {
+    #[topic]
+    pub seller: Address,
+}
+
+#[contractevent(topics = ["seller", "verified"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellerVerified {
+    #[topic]
+    pub seller: Address,
+    pub inspector: Address,
+}
+
+#[contractevent(topics = ["seller", "rejected"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellerRejected {
+    #[topic]
+    pub seller: Address,
+    pub inspector: Address,
+}
+
+#[contractevent(topics = ["buyer", "registered"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyerRegistered {
+    #[topic]
+    pub buyer: Address,
+}
+
+#[contractevent(topics = ["buyer", "updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyerUpdated {
+    #[topic]
+    pub buyer: Address,
+}
+
+#[contractevent(topics = ["buyer", "verified"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyerVerified {
+    #[topic]
+    pub buyer: Address,
+    pub inspector: Address,
+}
+
+#[contractevent(topics = ["buyer", "rejected"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyerRejected {
+    #[topic]
+    pub buyer: Address,
+    pub inspector: Address,
+}
+
+// Lands
+
+#[contractevent(topics = ["land", "added"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LandAdded {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub owner: Address,
+    pub land_price: i128,
+    pub is_fractional: bool,
+    pub total_fractions: u32,
+}
+
+#[contractevent(topics = ["land", "listed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LandListed {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub seller: Address,
+    pub price: i128,
+}
+
+#[contractevent(topics = ["land", "delisted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LandDelisted {
+    #[topic]
+    pub land_id: u32,
+    pub seller: Address,
+}
+
+#[contractevent(topics = ["land", "verified"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LandVerified {
+    #[topic]
+    pub land_id: u32,
+    pub inspector: Address,
+}
+
+#[contractevent(topics = ["land", "fractionalization_requested"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionalizationRequested {
+    #[topic]
+    pub land_id: u32,
+    pub owner: Address,
+    pub total_fractions: u32,
+    pub total_price: i128,
+    pub retained_fractions: u32,
+}
+
+#[contractevent(topics = ["land", "fractionalized"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LandFractionalized {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub inspector: Address,
+    pub owner: Address,
+    pub total_fractions: u32,
+    pub price_per_fraction: i128,
+}
+
+#[contractevent(topics = ["land", "fractionalization_rejected"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionalizationRejected {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub inspector: Address,
+    pub owner: Address,
+}
+
+// Requests and settlement
+
+#[contractevent(topics = ["request", "created"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestCreated {
+    #[topic]
+    pub req_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub buyer: Address,
+    pub seller: Address,
+    pub is_fractional_purchase: bool,
+}
+
+#[contractevent(topics = ["request", "approved"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestApproved {
+    #[topic]
+    pub req_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub seller: Address,
+}
+
+#[contractevent(topics = ["request", "paid"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestPaid {
+    #[topic]
+    pub req_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub buyer: Address,
+    pub amount: i128,
+    pub escrowed: bool,
+}
+
+#[contractevent(topics = ["request", "cancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestCancelled {
+    #[topic]
+    pub req_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub buyer: Address,
+}
+
+#[contractevent(topics = ["request", "rejected"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestRejected {
+    #[topic]
+    pub req_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub seller: Address,
+}
+
+#[contractevent(topics = ["request", "expired"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestExpired {
+    #[topic]
+    pub req_id: u32,
+    #[topic]
+    pub land_id: u32,
+}
+
+#[contractevent(topics = ["request", "windows_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestWindowsSet {
+    #[topic]
+    pub admin: Address,
+    pub approval_window: u64,
+    pub payment_window: u64,
+}
+
+#[contractevent(topics = ["fraction", "issued"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionIssued {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub owner: Address,
+    pub first_fraction_id: u32,
+    pub num_fractions: u32,
+}
+
+#[contractevent(topics = ["fraction", "listed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionListed {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub fraction_id: u32,
+    pub seller: Address,
+    pub price: i128,
+}
+
+#[contractevent(topics = ["fraction", "delisted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionDelisted {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub fraction_id: u32,
+    pub seller: Address,
+}
+
+// `price` is 0 for a transfer_fraction handover
+#[contractevent(topics = ["fraction", "transferred"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FractionTransferred {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub fraction_id: u32,
+    pub from: Address,
+    pub to: Address,
+    pub price: i128,
+}
+
+#[contractevent(topics = ["income", "deposited"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncomeDeposited {
+    #[topic]
+    pub land_id: u32,
+    pub from: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["income", "claimed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncomeClaimed {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub holder: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["governance", "config_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfigSet {
+    #[topic]
+    pub admin: Address,
+    pub quorum_bps: u32,
+    pub threshold_bps: u32,
+    pub voting_period: u64,
+}
+
+#[contractevent(topics = ["proposal", "created"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreated {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub proposer: Address,
+    pub action: ProposalAction,
+}
+
+#[contractevent(topics = ["proposal", "voted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCast {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub voter: Address,
+    pub support: bool,
+    pub weight: u32,
+}
+
+#[contractevent(topics = ["proposal", "executed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecuted {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub action: ProposalAction,
+}
+
+#[contractevent(topics = ["proposal", "defeated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalDefeated {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub land_id: u32,
+    pub votes_for: u32,
+    pub votes_against: u32,
+}
+
+#[contractevent(topics = ["buyout", "threshold_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyoutThresholdSet {
+    #[topic]
+    pub admin: Address,
+    pub threshold_bps: u32,
+}
+
+#[contractevent(topics = ["buyout", "completed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParcelBoughtOut {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub acquirer: Address,
+    pub previous_owner: Address,
+    pub price_per_fraction: i128,
+    pub escrowed: i128,
+}
+
+#[contractevent(topics = ["buyout", "claimed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyoutCompensationClaimed {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub holder: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["ownership", "transferred"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferred {
+    #[topic]
+    pub land_id: u32,
+    #[topic]
+    pub new_owner: Address,
+    pub previous_owner: Address,
+    pub req_id: u32,
+    pub inspector: Address,
+}
+
+#[contractevent(topics = ["escrow", "released"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReleased {
+    #[topic]
+    pub req_id: u32,
+    pub seller: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["escrow", "refunded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowRefunded {
+    #[topic]
+    pub req_id: u32,
+    pub buyer: Address,
+    pub amount: i128,
+}
@@ -0,0 +1,406 @@
+// Schema migrations run by `migrate` after an `upgrade`.
+// Each step rewrites the records stored under one schema version into the layout of the
+// next. When a layout changes, its previous shape is kept here as its own contracttype
+// so old records can still be decoded; new code never writes it.
+// A step addresses its records by a 0-based index so `migrate` can run it in batches;
+// `records` gives the size of that index space and `step` rewrites one slice of it.
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{
+    storage, DataKey, FractionalOwnership, LandListing, LandReg, LandRegistryContract, LandRequest, Party,
+    PartyRole, RequestStatus, MAX_FRACTIONS,
+};
+
+// LandRequest as stored in v1 and v2, with its state as two flags
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(crate) struct LandRequestV2 {
+    pub req_id: u32,
+    pub seller_id: Address,
+    pub buyer_id: Address,
+    pub land_id: u32,
+    pub approved: bool,
+    pub payment_received: bool,
+    pub is_fractional_purchase: bool,
+    pub fraction_id: Option<u32>,
+}
+
+// LandRequest as stored in v3, before created_at/updated_at
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(crate) struct LandRequestV3 {
+    pub req_id: u32,
+    pub seller_id: Address,
+    pub buyer_id: Address,
+    pub land_id: u32,
+    pub status: RequestStatus,
+    pub is_fractional_purchase: bool,
+    pub fraction_id: Option<u32>,
+}
+
+// LandRequest as stored in v4, before multi-fraction requests
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(crate) struct LandRequestV4 {
+    pub req_id: u32,
+    pub seller_id: Address,
+    pub buyer_id: Address,
+    pub land_id: u32,
+    pub status: RequestStatus,
+    pub is_fractional_purchase: bool,
+    pub fraction_id: Option<u32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+// FractionalOwnership as stored up to v4, with a rounded percentage per fraction
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(crate) struct FractionalOwnershipV4 {
+    pub land_id: u32,
+    pub owner: Address,
+    pub fraction_id: u32,
+    pub fraction_percentage: u32,
+    pub purchase_date: u64,
+}
+
+// Keys dropped from DataKey in v7. A contracttype enum key is encoded by variant name and
+// fields only, so these still address the entries written under DataKey.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(crate) enum DataKeyV6 {
+    Seller(Address),
+    Buyer(Address),
+    RegisteredAddress(Address),
+}
+
+// Buyer record as stored up to v6, before buyers and sellers became one Party
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(crate) struct BuyerV6 {
+    pub id: Address,
+    pub name: String,
+    pub age: u32,
+    pub city: String,
+    pub state: String,
+    pub aadhar_number: String,
+    pub pan_number: String,
+    pub document: String,
+    pub email: String,
+    pub verified: bool,
+    pub rejected: bool,
+}
+
+// Seller record as stored up to v6
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(crate) struct SellerV6 {
+    pub id: Address,
+    pub name: String,
+    pub age: u32,
+    pub city: String,
+    pub state: String,
+    pub aadhar_number: String,
+    pub pan_number: String,
+    pub lands_owned: String,
+    pub document: String,
+    pub verified: bool,
+    pub rejected: bool,
+}
+
+pub(crate) fn records(env: &Env, from_version: u32) -> u32 {
+    match from_version {
+        1 => land_count(env),
+        2 | 3 => request_count(env),
+        // Requests, then one slot per possible fraction of each land
+        4 => request_count(env) + land_count(env) * MAX_FRACTIONS,
+        5 => land_count(env),
+        // Sellers, then buyers
+        6 => party_list(env, DataKey::SellerList).len() + party_list(env, DataKey::BuyerList).len(),
+        // Lands, then requests
+        7 => land_count(env) + request_count(env),
+        8 => land_count(env),
+        _ => 0,
+    }
+}
+
+pub(crate) fn step(env: &Env, from_version: u32, start: u32, end: u32) {
+    for index in start..end {
+        match from_version {
+            1 => v1_to_v2(env, index),
+            2 => v2_to_v3(env, index),
+            3 => v3_to_v4(env, index),
+            4 => v4_to_v5(env, index),
+            5 => v5_to_v6(env, index),
+            6 => v6_to_v7(env, index),
+            7 => v7_to_v8(env, index),
+            8 => v8_to_v9(env, index),
+            _ => {}
+        }
+    }
+}
+
+fn land_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::LandCount).unwrap_or(0)
+}
+
+fn request_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::RequestCount).unwrap_or(0)
+}
+
+fn party_list(env: &Env, key: DataKey) -> Vec<Address> {
+    storage::get(env, &key).unwrap_or(Vec::new(env))
+}
+
+// v2 adds the PropertyPid / SurveyNumber uniqueness index. v1 never rejected duplicates,
+// so when two lands share a PID or survey number the lower land_id keeps the index entry.
+fn v1_to_v2(env: &Env, index: u32) {
+    let land_id = index + 1;
+    if let Some(land) = storage::get::<LandReg>(env, &DataKey::Land(land_id)) {
+        if !storage::has(env, &DataKey::PropertyPid(land.property_pid)) {
+            storage::set(env, &DataKey::PropertyPid(land.property_pid), &land.id);
+        }
+        let survey_key = LandRegistryContract::survey_key(&land);
+        if !storage::has(env, &survey_key) {
+            storage::set(env, &survey_key, &land.id);
+        }
+    }
+}
+
+// v3 replaces the approved/payment_received flags with RequestStatus
+fn v2_to_v3(env: &Env, index: u32) {
+    let key = DataKey::Request(index + 1);
+    if let Some(old) = storage::get::<LandRequestV2>(env, &key) {
+        let status = if old.payment_received {
+            RequestStatus::Paid
+        } else if old.approved {
+            RequestStatus::Approved
+        } else {
+            RequestStatus::Pending
+        };
+        let request = LandRequestV3 {
+            req_id: old.req_id,
+            seller_id: old.seller_id,
+            buyer_id: old.buyer_id,
+            land_id: old.land_id,
+            status,
+            is_fractional_purchase: old.is_fractional_purchase,
+            fraction_id: old.fraction_id,
+        };
+        storage::set(env, &key, &request);
+    }
+}
+
+// v4 timestamps requests. The original creation time is unknown, so both fields start at
+// the migration ledger, which also gives open requests a full expiry window.
+fn v3_to_v4(env: &Env, index: u32) {
+    let now = env.ledger().timestamp();
+    let key = DataKey::Request(index + 1);
+    if let Some(old) = storage::get::<LandRequestV3>(env, &key) {
+        let request = LandRequestV4 {
+            req_id: old.req_id,
+            seller_id: old.seller_id,
+            buyer_id: old.buyer_id,
+            land_id: old.land_id,
+            status: old.status,
+            is_fractional_purchase: old.is_fractional_purchase,
+            fraction_id: old.fraction_id,
+            created_at: now,
+            updated_at: now,
+        };
+        storage::set(env, &key, &request);
+    }
+}
+
+// v5 lets a request cover several fractions at a price fixed on approval, records fractions as
+// runs and counts holdings per holder. Earlier fractional requests were always for a single
+// fraction, and each holder owned exactly one.
+fn v4_to_v5(env: &Env, index: u32) {
+    let request_count = request_count(env);
+    if index < request_count {
+        let key = DataKey::Request(index + 1);
+        if let Some(old) = storage::get::<LandRequestV4>(env, &key) {
+            // Approved requests keep the price they would have paid at upgrade time
+            let price = match storage::get::<LandReg>(env, &DataKey::Land(old.land_id)) {
+                Some(land) if old.status == RequestStatus::Approved => {
+                    if old.is_fractional_purchase { land.price_per_fraction } else { land.land_price }
+                }
+                _ => 0,
+            };
+            let request = LandRequest {
+                req_id: old.req_id,
+                seller_id: old.seller_id,
+                buyer_id: old.buyer_id,
+                land_id: old.land_id,
+                status: old.status,
+                is_fractional_purchase: old.is_fractional_purchase,
+                fraction_id: old.fraction_id,
+                num_fractions: if old.is_fractional_purchase { 1 } else { 0 },
+                price,
+                created_at: old.created_at,
+                updated_at: old.updated_at,
+            };
+            storage::set(env, &key, &request);
+        }
+        return;
+    }
+
+    // Each fraction becomes a run of one, visited in id order so the run index stays sorted
+    let slot = index - request_count;
+    let land_id = slot / MAX_FRACTIONS + 1;
+    let key = DataKey::FractionalOwnership(land_id, slot % MAX_FRACTIONS + 1);
+    if let Some(old) = storage::get::<FractionalOwnershipV4>(env, &key) {
+        let holder_key = DataKey::HolderFractions(land_id, old.owner.clone());
+        let held: u32 = storage::get(env, &holder_key).unwrap_or(0);
+        storage::set(env, &holder_key, &(held + 1));
+
+        let mut runs: Vec<u32> = storage::get(env, &DataKey::FractionRuns(land_id)).unwrap_or(Vec::new(env));
+        runs.push_back(old.fraction_id);
+        storage::set(env, &DataKey::FractionRuns(land_id), &runs);
+
+        let fraction = FractionalOwnership {
+            land_id: old.land_id,
+            owner: old.owner,
+            fraction_id: old.fraction_id,
+            num_fractions: 1,
+            purchase_date: old.purchase_date,
+        };
+        storage::set(env, &key, &fraction);
+    }
+}
+
+// v6 has the land owner hold every unsold fraction. Income earned on them so far was already
+// credited to the owner, so crediting them now starts the owner's checkpoint at the current total.
+fn v5_to_v6(env: &Env, index: u32) {
+    let land_id = index + 1;
+    let Some(land) = storage::get::<LandReg>(env, &DataKey::Land(land_id)) else {
+        return;
+    };
+    let unsold = land.total_fractions - land.fractions_sold;
+    if !land.is_fractional || unsold == 0 {
+        return;
+    }
+    if let Some(owner) = storage::get::<Address>(env, &DataKey::LandOwner(land_id)) {
+        LandRegistryContract::credit_fractions(env, land_id, &owner, unsold);
+    }
+}
+
+// v7 turns each Seller and Buyer record into a Party with the matching role. Registration
+// refused a second role before, but should an address have both records they are merged, and
+// the party only stays verified if both records were.
+fn v6_to_v7(env: &Env, index: u32) {
+    let sellers = party_list(env, DataKey::SellerList);
+    if index < sellers.len() {
+        let address = sellers.get_unchecked(index);
+        let key = DataKeyV6::Seller(address.clone());
+        if let Some(old) = env.storage().persistent().get::<_, SellerV6>(&key) {
+            let party = Party {
+                id: old.id,
+                name: old.name,
+                age: old.age,
+                city: old.city,
+                state: old.state,
+                aadhar_number: old.aadhar_number,
+                pan_number: old.pan_number,
+                document: old.document,
+                email: String::from_str(env, ""),
+                lands_owned: old.lands_owned,
+                role: PartyRole::Seller,
+                verified: old.verified,
+                rejected: old.rejected,
+            };
+            storage::set(env, &DataKey::Party(address.clone()), &party);
+            env.storage().persistent().remove(&key);
+        }
+        env.storage().persistent().remove(&DataKeyV6::RegisteredAddress(address));
+        return;
+    }
+
+    let address = party_list(env, DataKey::BuyerList).get_unchecked(index - sellers.len());
+    let key = DataKeyV6::Buyer(address.clone());
+    if let Some(old) = env.storage().persistent().get::<_, BuyerV6>(&key) {
+        let party = match storage::get::<Party>(env, &DataKey::Party(address.clone())) {
+            Some(seller) => Party {
+                email: old.email,
+                role: PartyRole::Both,
+                verified: seller.verified && old.verified,
+                rejected: seller.rejected || old.rejected,
+                ..seller
+            },
+            None => Party {
+                id: old.id,
+                name: old.name,
+                age: old.age,
+                city: old.city,
+                state: old.state,
+                aadhar_number: old.aadhar_number,
+                pan_number: old.pan_number,
+                document: old.document,
+                email: old.email,
+                lands_owned: String::from_str(env, ""),
+                role: PartyRole::Buyer,
+                verified: old.verified,
+                rejected: old.rejected,
+            },
+        };
+        storage::set(env, &DataKey::Party(address.clone()), &party);
+        env.storage().persistent().remove(&key);
+    }
+    env.storage().persistent().remove(&DataKeyV6::RegisteredAddress(address));
+}
+
+// v8 only lets listed whole lands be requested. Every whole land was open to requests before,
+// so each one starts out listed by its current owner at its current price. A listing also
+// records the sale whose payment is in escrow; where several were paid, the latest is kept and
+// the others can still be transferred or refunded.
+fn v7_to_v8(env: &Env, index: u32) {
+    let land_count = land_count(env);
+    if index >= land_count {
+        let req_id = index - land_count + 1;
+        let Some(request) = storage::get::<LandRequest>(env, &DataKey::Request(req_id)) else {
+            return;
+        };
+        if request.is_fractional_purchase || request.status != RequestStatus::Paid {
+            return;
+        }
+        let key = DataKey::LandListing(request.land_id);
+        if let Some(mut listing) = storage::get::<LandListing>(env, &key) {
+            listing.pending_sale = Some(req_id);
+            storage::set(env, &key, &listing);
+        }
+        return;
+    }
+
+    let land_id = index + 1;
+    let Some(land) = storage::get::<LandReg>(env, &DataKey::Land(land_id)) else {
+        return;
+    };
+    if land.is_fractional {
+        return;
+    }
+    if let Some(owner) = storage::get::<Address>(env, &DataKey::LandOwner(land_id)) {
+        let listing = LandListing {
+            land_id,
+            seller: owner,
+            price: land.land_price,
+            listed_at: env.ledger().timestamp(),
+            pending_sale: None,
+        };
+        storage::set(env, &DataKey::LandListing(land_id), &listing);
+    }
+}
+
+// v9 keeps a chain of title per land. Earlier owners were never recorded, so each chain
+// starts with the owner at migration time, at the land's current price.
+fn v8_to_v9(env: &Env, index: u32) {
+    let land_id = index + 1;
+    let Some(land) = storage::get::<LandReg>(env, &DataKey::Land(land_id)) else {
+        return;
+    };
+    if storage::has(env, &DataKey::TitleLength(land_id)) {
+        return;
+    }
+    if let Some(owner) = storage::get::<Address>(env, &DataKey::LandOwner(land_id)) {
+        LandRegistryContract::record_title(env, land_id, &owner, None, land.land_price, None);
+    }
+}
@@ -0,0 +1,48 @@
+// Storage helpers and TTL policy.
+// Counters and config live in instance storage; every per-entity record (lands, parties,
+// requests, fractions, escrows) lives in persistent storage and is extended on write so
+// land titles are never archived. Anyone can also extend them via `bump_land`/`bump_party`.
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val};
+
+use crate::DataKey;
+
+const DAY_IN_LEDGERS: u32 = 17280; // ~5s ledger close time
+
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+pub(crate) fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub(crate) fn has(env: &Env, key: &DataKey) -> bool {
+    env.storage().persistent().has(key)
+}
+
+pub(crate) fn get<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    env.storage().persistent().get(key)
+}
+
+pub(crate) fn set<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    extend_instance(env);
+}
+
+// Extends a persistent entry if present; returns whether it existed
+pub(crate) fn bump(env: &Env, key: &DataKey) -> bool {
+    if !has(env, key) {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    true
+}
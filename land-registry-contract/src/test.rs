@@ -1,25 +1,192 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Events as _, Ledger},
+    token, vec, Address, Env, Event, String, Val,
+};
+
+// Pinned release builds of the registry at schema versions 1 and 2, used to exercise `upgrade`
+mod land_registry_v1 {
+    soroban_sdk::contractimport!(file = "test_wasms/land_registry_v1.wasm");
+}
+
+mod land_registry_v2 {
+    soroban_sdk::contractimport!(file = "test_wasms/land_registry_v2.wasm");
+}
+
+fn create_token<'a>(env: &Env) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    (
+        token::Client::new(env, &sac.address()),
+        token::StellarAssetClient::new(env, &sac.address()),
+    )
+}
+
+fn setup_contract<'a>(
+    env: &Env,
+) -> (LandRegistryContractClient<'a>, Address, token::Client<'a>, token::StellarAssetClient<'a>) {
+    let contract_id = env.register(LandRegistryContract, ());
+    let client = LandRegistryContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let inspector = Address::generate(env);
+    let (token, token_admin) = create_token(env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
+        &inspector,
+        &String::from_str(env, "Inspector 1"),
+        &45,
+        &String::from_str(env, "Tehsil Manager"),
+        &state_wide(env, "Maharashtra")
+    );
+
+    (client, inspector, token, token_admin)
+}
+
+fn state_wide(env: &Env, state: &str) -> Vec<Jurisdiction> {
+    vec![env, Jurisdiction {
+        state: String::from_str(env, state),
+        city: String::from_str(env, "*"),
+    }]
+}
+
+fn register_verified_seller(env: &Env, client: &LandRegistryContractClient, inspector: &Address) -> Address {
+    let seller = Address::generate(env);
+    client.register_seller(
+        &seller,
+        &String::from_str(env, "Seller"),
+        &30,
+        &String::from_str(env, "Akola"),
+        &String::from_str(env, "Maharashtra"),
+        &String::from_str(env, "abc"),
+        &String::from_str(env, "xyz"),
+        &String::from_str(env, "many"),
+        &String::from_str(env, "QmHash")
+    );
+    client.verify_seller(inspector, &seller);
+    seller
+}
+
+fn register_verified_buyer(env: &Env, client: &LandRegistryContractClient, inspector: &Address) -> Address {
+    let buyer = Address::generate(env);
+    client.register_buyer(
+        &buyer,
+        &String::from_str(env, "Buyer"),
+        &25,
+        &String::from_str(env, "Mumbai"),
+        &String::from_str(env, "Maharashtra"),
+        &String::from_str(env, "aadhar"),
+        &String::from_str(env, "pan"),
+        &String::from_str(env, "QmHash"),
+        &String::from_str(env, "buyer@email.com")
+    );
+    client.verify_buyer(inspector, &buyer);
+    buyer
+}
+
+// Registers an existing buyer as a seller too and verifies the party again
+fn add_seller_role(env: &Env, client: &LandRegistryContractClient, inspector: &Address, party: &Address) {
+    client.register_seller(
+        party,
+        &String::from_str(env, "Buyer"),
+        &25,
+        &String::from_str(env, "Mumbai"),
+        &String::from_str(env, "Maharashtra"),
+        &String::from_str(env, "aadhar"),
+        &String::from_str(env, "pan"),
+        &String::from_str(env, "one"),
+        &String::from_str(env, "QmHash")
+    );
+    client.verify_seller(inspector, party);
+}
+
+fn add_small_fractional_land(env: &Env, client: &LandRegistryContractClient, seller: &Address, total_fractions: u32) -> u32 {
+    client.add_fractional_land(
+        seller,
+        &1000,
+        &String::from_str(env, "Akola"),
+        &String::from_str(env, "Maharashtra"),
+        &100000,
+        &777,
+        &2024,
+        &String::from_str(env, "QmHash"),
+        &String::from_str(env, "QmHash"),
+        &total_fractions
+    );
+    client.get_lands_count()
+}
+
+// Seller lists a fractional land of `total_fractions` and `buyer` buys `num_fractions` of it
+fn buy_fractions(
+    env: &Env,
+    client: &LandRegistryContractClient,
+    inspector: &Address,
+    token_admin: &token::StellarAssetClient,
+    seller: &Address,
+    buyer: &Address,
+    total_fractions: u32,
+    num_fractions: u32,
+) -> u32 {
+    let land_id = add_small_fractional_land(env, client, seller, total_fractions);
+    client.verify_land(inspector, &land_id);
+    token_admin.mint(buyer, &100000);
+    client.request_fractional_land(buyer, &land_id, &num_fractions);
+    let req_id = client.get_requests_count();
+    client.approve_request(seller, &req_id);
+    client.payment(buyer, &req_id);
+    land_id
+}
+
+// Events are only retained for the most recent top-level invocation
+fn assert_last_event(env: &Env, contract_id: &Address, event: impl Event) {
+    let last = env.events().all().iter()
+        .filter(|(id, _, _)| id == contract_id)
+        .last()
+        .expect("no contract events");
+    let expected: (Address, Vec<Val>, Val) = (contract_id.clone(), event.topics(env), event.data(env));
+    assert_eq!(vec![env, last], vec![env, expected]);
+}
+
+fn add_sample_land(env: &Env, client: &LandRegistryContractClient, seller: &Address) -> u32 {
+    client.add_land(
+        seller,
+        &500,
+        &String::from_str(env, "Akola"),
+        &String::from_str(env, "Maharashtra"),
+        &20000,
+        &567,
+        &1890,
+        &String::from_str(env, "QmHash"),
+        &String::from_str(env, "QmHash")
+    );
+    client.get_lands_count()
+}
 
 #[test]
 fn test_initialize() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
-    let inspector = Address::generate(&env);
-    let name = String::from_str(&env, "Inspector 1");
-    let designation = String::from_str(&env, "Tehsil Manager");
+    let admin = Address::generate(&env);
+    let (token, _) = create_token(&env);
     
-    client.initialize(&inspector, &name, &45, &designation);
+    // The admin must sign the call that names them
+    assert!(client.try_initialize(&admin, &token.address).is_err());
+
+    env.mock_all_auths();
+    client.initialize(&admin, &token.address);
     
     assert_eq!(client.get_lands_count(), 0);
     assert_eq!(client.get_sellers_count(), 0);
     assert_eq!(client.get_buyers_count(), 0);
     assert_eq!(client.get_requests_count(), 0);
-    assert!(client.is_land_inspector(&inspector));
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_payment_token(), token.address);
+    assert_eq!(client.list_inspectors().len(), 0);
 }
 
 #[test]
@@ -27,15 +194,20 @@ fn test_register_seller() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -43,6 +215,8 @@ fn test_register_seller() {
         &seller,
         &String::from_str(&env, "Vrinda"),
         &20,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -54,7 +228,7 @@ fn test_register_seller() {
     let seller_data = client.get_seller(&seller);
     assert_eq!(seller_data.name, String::from_str(&env, "Vrinda"));
     assert_eq!(seller_data.age, 20);
-    assert_eq!(seller_data.verified, false);
+    assert!(!seller_data.verified);
 }
 
 #[test]
@@ -62,15 +236,20 @@ fn test_register_buyer() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let buyer = Address::generate(&env);
@@ -79,6 +258,7 @@ fn test_register_buyer() {
         &String::from_str(&env, "Vrinda"),
         &20,
         &String::from_str(&env, "akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar123456"),
         &String::from_str(&env, "pan1234567"),
         &String::from_str(&env, "QmYdztkcPJLmGmwLmM4nyBfVatoBMRDuUjmgBupjmTodAP"),
@@ -90,7 +270,7 @@ fn test_register_buyer() {
     let buyer_data = client.get_buyer(&buyer);
     assert_eq!(buyer_data.name, String::from_str(&env, "Vrinda"));
     assert_eq!(buyer_data.age, 20);
-    assert_eq!(buyer_data.verified, false);
+    assert!(!buyer_data.verified);
 }
 
 #[test]
@@ -98,15 +278,20 @@ fn test_verify_seller() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -114,6 +299,8 @@ fn test_verify_seller() {
         &seller,
         &String::from_str(&env, "Vrinda"),
         &20,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -132,15 +319,20 @@ fn test_verify_buyer() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let buyer = Address::generate(&env);
@@ -149,6 +341,7 @@ fn test_verify_buyer() {
         &String::from_str(&env, "Vrinda"),
         &20,
         &String::from_str(&env, "akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar123456"),
         &String::from_str(&env, "pan1234567"),
         &String::from_str(&env, "QmYdztkcPJLmGmwLmM4nyBfVatoBMRDuUjmgBupjmTodAP"),
@@ -167,15 +360,20 @@ fn test_add_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -183,6 +381,8 @@ fn test_add_land() {
         &seller,
         &String::from_str(&env, "Vrinda"),
         &20,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -209,7 +409,7 @@ fn test_add_land() {
     assert_eq!(land.area, 500);
     assert_eq!(land.city, String::from_str(&env, "Akola"));
     assert_eq!(land.land_price, 20000);
-    assert_eq!(land.is_fractional, false);
+    assert!(!land.is_fractional);
     
     let owner = client.get_land_owner(&1);
     assert_eq!(owner, seller);
@@ -220,15 +420,20 @@ fn test_add_fractional_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -236,6 +441,8 @@ fn test_add_fractional_land() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -260,7 +467,7 @@ fn test_add_fractional_land() {
     assert_eq!(client.get_lands_count(), 1);
     
     let land = client.get_land(&1);
-    assert_eq!(land.is_fractional, true);
+    assert!(land.is_fractional);
     assert_eq!(land.total_fractions, 10);
     assert_eq!(land.fractions_sold, 0);
     assert_eq!(land.price_per_fraction, 100000);
@@ -272,15 +479,20 @@ fn test_request_fractional_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -288,6 +500,8 @@ fn test_request_fractional_land() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -314,6 +528,7 @@ fn test_request_fractional_land() {
         &String::from_str(&env, "Buyer1"),
         &25,
         &String::from_str(&env, "Delhi"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
@@ -321,13 +536,14 @@ fn test_request_fractional_land() {
     );
     client.verify_buyer(&inspector, &buyer);
     
-    client.request_fractional_land(&buyer, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_fractional_land(&buyer, &1, &1);
     
     assert_eq!(client.get_requests_count(), 1);
     
     let request = client.get_request(&1);
-    assert_eq!(request.is_fractional_purchase, true);
-    assert_eq!(request.fraction_id, Some(1));
+    assert!(request.is_fractional_purchase);
+    assert_eq!(request.fraction_id, None);
 }
 
 #[test]
@@ -335,15 +551,20 @@ fn test_multiple_buyers_fractional_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -351,6 +572,8 @@ fn test_multiple_buyers_fractional_land() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -374,7 +597,7 @@ fn test_multiple_buyers_fractional_land() {
     
     // Create and verify 10 different buyers
     let mut buyers = Vec::new(&env);
-    for i in 0..10 {
+    for _ in 0..10 {
         let buyer = Address::generate(&env);
         let name = String::from_str(&env, "Buyer");
         client.register_buyer(
@@ -382,20 +605,24 @@ fn test_multiple_buyers_fractional_land() {
             &name,
             &25,
             &String::from_str(&env, "City"),
+            &String::from_str(&env, "Maharashtra"),
             &String::from_str(&env, "aadhar"),
             &String::from_str(&env, "pan"),
             &String::from_str(&env, "QmHash"),
             &String::from_str(&env, "buyer@email.com")
         );
         client.verify_buyer(&inspector, &buyer);
+        token_admin.mint(&buyer, &100000);
         buyers.push_back(buyer);
     }
     
+    client.verify_land(&inspector, &1);
+
     // Each buyer requests, gets approved, and pays for a fraction
     for i in 0..10 {
         let buyer = buyers.get(i).unwrap();
         
-        client.request_fractional_land(&buyer, &seller, &1);
+        client.request_fractional_land(&buyer, &1, &1);
         let req_id = i + 1;
         client.approve_request(&seller, &req_id);
         client.payment(&buyer, &req_id);
@@ -412,12 +639,13 @@ fn test_multiple_buyers_fractional_land() {
     
     let owners = client.get_land_fraction_owners(&1);
     assert_eq!(owners.len(), 10);
+    assert_eq!(token.balance(&seller), 1000000);
     
     // Check individual fractional ownership
     for i in 0..10 {
         let fraction = client.get_fractional_ownership(&1, &(i + 1));
         assert_eq!(fraction.land_id, 1);
-        assert_eq!(fraction.fraction_percentage, 10);
+        assert_eq!(client.get_holder_share(&1, &fraction.owner), (1, 10));
     }
 }
 
@@ -426,15 +654,20 @@ fn test_request_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -442,6 +675,8 @@ fn test_request_land() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -467,6 +702,7 @@ fn test_request_land() {
         &String::from_str(&env, "Buyer"),
         &25,
         &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
@@ -474,7 +710,8 @@ fn test_request_land() {
     );
     client.verify_buyer(&inspector, &buyer);
     
-    client.request_land(&buyer, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_land(&buyer, &1);
     
     assert_eq!(client.get_requests_count(), 1);
     
@@ -482,8 +719,8 @@ fn test_request_land() {
     assert_eq!(request.seller_id, seller);
     assert_eq!(request.buyer_id, buyer);
     assert_eq!(request.land_id, 1);
-    assert!(!request.approved);
-    assert_eq!(request.is_fractional_purchase, false);
+    assert_eq!(request.status, RequestStatus::Pending);
+    assert!(!request.is_fractional_purchase);
 }
 
 #[test]
@@ -491,15 +728,20 @@ fn test_approve_request() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -507,6 +749,8 @@ fn test_approve_request() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -532,6 +776,7 @@ fn test_approve_request() {
         &String::from_str(&env, "Buyer"),
         &25,
         &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
@@ -539,11 +784,12 @@ fn test_approve_request() {
     );
     client.verify_buyer(&inspector, &buyer);
     
-    client.request_land(&buyer, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_land(&buyer, &1);
     client.approve_request(&seller, &1);
     
     let request = client.get_request(&1);
-    assert!(request.approved);
+    assert_eq!(request.status, RequestStatus::Approved);
 }
 
 #[test]
@@ -551,15 +797,20 @@ fn test_payment() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -567,6 +818,8 @@ fn test_payment() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -592,19 +845,100 @@ fn test_payment() {
         &String::from_str(&env, "Buyer"),
         &25,
         &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
         &String::from_str(&env, "buyer@email.com")
     );
     client.verify_buyer(&inspector, &buyer);
+    token_admin.mint(&buyer, &20000);
     
-    client.request_land(&buyer, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_land(&buyer, &1);
     client.approve_request(&seller, &1);
     client.payment(&buyer, &1);
     
     let request = client.get_request(&1);
-    assert!(request.payment_received);
+    assert_eq!(request.status, RequestStatus::Paid);
+    assert_eq!(token.balance(&buyer), 0);
+    assert_eq!(token.balance(&seller), 0);
+    assert_eq!(token.balance(&contract_id), 20000);
+    
+    let escrow = client.get_escrow(&1);
+    assert_eq!(escrow.amount, 20000);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+}
+
+#[test]
+#[should_panic]
+fn test_payment_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register(LandRegistryContract, ());
+    let client = LandRegistryContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let inspector = Address::generate(&env);
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
+        &inspector,
+        &String::from_str(&env, "Inspector 1"),
+        &45,
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
+    );
+    
+    let seller = Address::generate(&env);
+    client.register_seller(
+        &seller,
+        &String::from_str(&env, "Seller"),
+        &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "abc"),
+        &String::from_str(&env, "xyz"),
+        &String::from_str(&env, "many"),
+        &String::from_str(&env, "QmHash")
+    );
+    client.verify_seller(&inspector, &seller);
+    
+    client.add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &567,
+        &1890,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    
+    let buyer = Address::generate(&env);
+    client.register_buyer(
+        &buyer,
+        &String::from_str(&env, "Buyer"),
+        &25,
+        &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "aadhar"),
+        &String::from_str(&env, "pan"),
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "buyer@email.com")
+    );
+    client.verify_buyer(&inspector, &buyer);
+    token_admin.mint(&buyer, &19999);
+    
+    client.verify_land(&inspector, &1);
+    client.request_land(&buyer, &1);
+    client.approve_request(&seller, &1);
+    
+    // Should panic - buyer cannot cover the land price
+    client.payment(&buyer, &1);
 }
 
 #[test]
@@ -612,15 +946,20 @@ fn test_transfer_ownership() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -628,6 +967,8 @@ fn test_transfer_ownership() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -653,21 +994,27 @@ fn test_transfer_ownership() {
         &String::from_str(&env, "Buyer"),
         &25,
         &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
         &String::from_str(&env, "buyer@email.com")
     );
     client.verify_buyer(&inspector, &buyer);
+    token_admin.mint(&buyer, &20000);
     
-    client.request_land(&buyer, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_land(&buyer, &1);
     client.approve_request(&seller, &1);
     client.payment(&buyer, &1);
     
-    client.transfer_ownership(&inspector, &1, &buyer);
+    client.transfer_ownership(&inspector, &1);
     
     let new_owner = client.get_land_owner(&1);
     assert_eq!(new_owner, buyer);
+    assert_eq!(token.balance(&seller), 20000);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(client.get_escrow(&1).status, EscrowStatus::Released);
 }
 
 #[test]
@@ -675,15 +1022,20 @@ fn test_update_seller() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -691,6 +1043,8 @@ fn test_update_seller() {
         &seller,
         &String::from_str(&env, "Vrinda"),
         &20,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -703,6 +1057,8 @@ fn test_update_seller() {
         &seller,
         &String::from_str(&env, "Vrinda Ahuja"),
         &21,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar123456"),
         &String::from_str(&env, "pannumber"),
         &String::from_str(&env, "ten")
@@ -719,15 +1075,20 @@ fn test_verify_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -735,6 +1096,8 @@ fn test_verify_land() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -764,15 +1127,20 @@ fn test_get_user_fractional_lands() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -780,6 +1148,8 @@ fn test_get_user_fractional_lands() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -806,14 +1176,17 @@ fn test_get_user_fractional_lands() {
         &String::from_str(&env, "Buyer"),
         &25,
         &String::from_str(&env, "Delhi"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
         &String::from_str(&env, "buyer@email.com")
     );
     client.verify_buyer(&inspector, &buyer);
+    token_admin.mint(&buyer, &100000);
     
-    client.request_fractional_land(&buyer, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_fractional_land(&buyer, &1, &1);
     client.approve_request(&seller, &1);
     client.payment(&buyer, &1);
     
@@ -823,44 +1196,50 @@ fn test_get_user_fractional_lands() {
 }
 
 #[test]
-#[should_panic(expected = "Contract already initialized")]
 fn test_double_initialization() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    env.mock_all_auths();
+    
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
-    // Should panic
-    client.initialize(
-        &inspector,
-        &String::from_str(&env, "Inspector 2"),
-        &50,
-        &String::from_str(&env, "Manager")
-    );
+    // Should fail
+    let result = client.try_initialize(&inspector, &token.address);
+    assert_eq!(result, Err(Ok(RegistryError::AlreadyInitialized)));
 }
 
 #[test]
-#[should_panic(expected = "Address already registered")]
 fn test_double_registration() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -868,39 +1247,48 @@ fn test_double_registration() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
         &String::from_str(&env, "QmHash")
     );
     
-    // Should panic
-    client.register_seller(
+    // Should fail
+    let result = client.try_register_seller(
         &seller,
         &String::from_str(&env, "Seller 2"),
         &35,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "def"),
         &String::from_str(&env, "uvw"),
         &String::from_str(&env, "some"),
         &String::from_str(&env, "QmHash2")
     );
+    assert_eq!(result, Err(Ok(RegistryError::AlreadyRegistered)));
 }
 
 #[test]
-#[should_panic(expected = "All fractions have been sold")]
 fn test_fractional_land_sold_out() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -908,6 +1296,8 @@ fn test_fractional_land_sold_out() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -920,7 +1310,7 @@ fn test_fractional_land_sold_out() {
         &seller,
         &1000,
         &String::from_str(&env, "City"),
-        &String::from_str(&env, "State"),
+        &String::from_str(&env, "Maharashtra"),
         &100000,
         &123,
         &456,
@@ -936,12 +1326,14 @@ fn test_fractional_land_sold_out() {
         &String::from_str(&env, "Buyer1"),
         &25,
         &String::from_str(&env, "City"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
         &String::from_str(&env, "buyer1@email.com")
     );
     client.verify_buyer(&inspector, &buyer1);
+    token_admin.mint(&buyer1, &50000);
     
     let buyer2 = Address::generate(&env);
     client.register_buyer(
@@ -949,12 +1341,14 @@ fn test_fractional_land_sold_out() {
         &String::from_str(&env, "Buyer2"),
         &25,
         &String::from_str(&env, "City"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
         &String::from_str(&env, "buyer2@email.com")
     );
     client.verify_buyer(&inspector, &buyer2);
+    token_admin.mint(&buyer2, &50000);
     
     let buyer3 = Address::generate(&env);
     client.register_buyer(
@@ -962,6 +1356,7 @@ fn test_fractional_land_sold_out() {
         &String::from_str(&env, "Buyer3"),
         &25,
         &String::from_str(&env, "City"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
@@ -970,33 +1365,39 @@ fn test_fractional_land_sold_out() {
     client.verify_buyer(&inspector, &buyer3);
     
     // First two buyers purchase successfully
-    client.request_fractional_land(&buyer1, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_fractional_land(&buyer1, &1, &1);
     client.approve_request(&seller, &1);
     client.payment(&buyer1, &1);
     
-    client.request_fractional_land(&buyer2, &seller, &1);
+    client.request_fractional_land(&buyer2, &1, &1);
     client.approve_request(&seller, &2);
     client.payment(&buyer2, &2);
     
-    // Third buyer tries to purchase - should panic
-    client.request_fractional_land(&buyer3, &seller, &1);
+    // Third buyer tries to purchase - should fail
+    let result = client.try_request_fractional_land(&buyer3, &1, &1);
+    assert_eq!(result, Err(Ok(RegistryError::AllFractionsSold)));
 }
 
 #[test]
-#[should_panic(expected = "Buyer already owns a fraction of this land")]
-fn test_buyer_cannot_buy_twice() {
+fn test_buyer_can_add_to_holding() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -1004,6 +1405,8 @@ fn test_buyer_cannot_buy_twice() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -1015,7 +1418,7 @@ fn test_buyer_cannot_buy_twice() {
         &seller,
         &1000,
         &String::from_str(&env, "City"),
-        &String::from_str(&env, "State"),
+        &String::from_str(&env, "Maharashtra"),
         &100000,
         &123,
         &456,
@@ -1030,37 +1433,51 @@ fn test_buyer_cannot_buy_twice() {
         &String::from_str(&env, "Buyer"),
         &25,
         &String::from_str(&env, "City"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
         &String::from_str(&env, "buyer@email.com")
     );
     client.verify_buyer(&inspector, &buyer);
+    token_admin.mint(&buyer, &40000);
     
     // First purchase
-    client.request_fractional_land(&buyer, &seller, &1);
+    client.verify_land(&inspector, &1);
+    client.request_fractional_land(&buyer, &1, &1);
     client.approve_request(&seller, &1);
     client.payment(&buyer, &1);
     
-    // Second purchase attempt - should panic
-    client.request_fractional_land(&buyer, &seller, &1);
+    // A second purchase adds to the same holding
+    client.request_fractional_land(&buyer, &1, &1);
+    client.approve_request(&seller, &2);
+    client.payment(&buyer, &2);
+
+    assert_eq!(client.get_holder_share(&1, &buyer), (2, 5));
+    assert_eq!(client.get_holder_share(&1, &seller), (3, 5));
+    assert_eq!(client.get_land_fraction_owners(&1), vec![&env, seller.clone(), buyer.clone()]);
+    assert_eq!(client.get_user_fractional_lands(&buyer).len(), 1);
 }
 
 #[test]
-#[should_panic(expected = "This is fractional land, use request_fractional_land instead")]
 fn test_wrong_request_method_for_fractional_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, _) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -1068,6 +1485,8 @@ fn test_wrong_request_method_for_fractional_land() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -1079,7 +1498,7 @@ fn test_wrong_request_method_for_fractional_land() {
         &seller,
         &1000,
         &String::from_str(&env, "City"),
-        &String::from_str(&env, "State"),
+        &String::from_str(&env, "Maharashtra"),
         &100000,
         &123,
         &456,
@@ -1094,6 +1513,7 @@ fn test_wrong_request_method_for_fractional_land() {
         &String::from_str(&env, "Buyer"),
         &25,
         &String::from_str(&env, "City"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "aadhar"),
         &String::from_str(&env, "pan"),
         &String::from_str(&env, "QmHash"),
@@ -1101,25 +1521,31 @@ fn test_wrong_request_method_for_fractional_land() {
     );
     client.verify_buyer(&inspector, &buyer);
     
-    // Using wrong method - should panic
-    client.request_land(&buyer, &seller, &1);
+    // Using wrong method - should fail
+    client.verify_land(&inspector, &1);
+    let result = client.try_request_land(&buyer, &1);
+    assert_eq!(result, Err(Ok(RegistryError::LandIsFractional)));
 }
 
 #[test]
-#[should_panic(expected = "Cannot transfer ownership of fractional land")]
 fn test_cannot_transfer_fractional_land() {
     let env = Env::default();
     env.mock_all_auths();
     
-    let contract_id = env.register_contract(None, LandRegistryContract);
+    let contract_id = env.register(LandRegistryContract, ());
     let client = LandRegistryContractClient::new(&env, &contract_id);
     
+    let admin = Address::generate(&env);
     let inspector = Address::generate(&env);
-    client.initialize(
+    let (token, token_admin) = create_token(&env);
+    client.initialize(&admin, &token.address);
+    client.add_inspector(
+        &admin,
         &inspector,
         &String::from_str(&env, "Inspector 1"),
         &45,
-        &String::from_str(&env, "Tehsil Manager")
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
     );
     
     let seller = Address::generate(&env);
@@ -1127,6 +1553,8 @@ fn test_cannot_transfer_fractional_land() {
         &seller,
         &String::from_str(&env, "Seller"),
         &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
         &String::from_str(&env, "abc"),
         &String::from_str(&env, "xyz"),
         &String::from_str(&env, "many"),
@@ -1138,7 +1566,7 @@ fn test_cannot_transfer_fractional_land() {
         &seller,
         &1000,
         &String::from_str(&env, "City"),
-        &String::from_str(&env, "State"),
+        &String::from_str(&env, "Maharashtra"),
         &100000,
         &123,
         &456,
@@ -1148,7 +1576,2817 @@ fn test_cannot_transfer_fractional_land() {
     );
     
     let buyer = Address::generate(&env);
+    client.register_buyer(
+        &buyer,
+        &String::from_str(&env, "Buyer"),
+        &25,
+        &String::from_str(&env, "City"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "aadhar"),
+        &String::from_str(&env, "pan"),
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "buyer@email.com")
+    );
+    client.verify_buyer(&inspector, &buyer);
+    token_admin.mint(&buyer, &10000);
+    
+    client.verify_land(&inspector, &1);
+    client.request_fractional_land(&buyer, &1, &1);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
     
-    // Should panic - cannot transfer fractional land
-    client.transfer_ownership(&inspector, &1, &buyer);
-}
\ No newline at end of file
+    // Should fail - cannot transfer fractional land
+    let result = client.try_transfer_ownership(&inspector, &1);
+    assert_eq!(result, Err(Ok(RegistryError::CannotTransferFractional)));
+}
+
+#[test]
+fn test_transfer_requires_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+
+    // Should fail - request was approved but never paid
+    let result = client.try_transfer_ownership(&inspector, &1);
+    assert_eq!(result, Err(Ok(RegistryError::PaymentNotReceived)));
+}
+
+#[test]
+fn test_refund_escrow_after_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &20000);
+
+    client.set_escrow_period(&client.get_admin(), &1000);
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+    assert_eq!(token.balance(&buyer), 0);
+
+    env.ledger().set_timestamp(client.get_escrow(&1).deadline);
+    client.refund_escrow(&buyer, &1);
+
+    assert_eq!(token.balance(&buyer), 20000);
+    assert_eq!(token.balance(&seller), 0);
+    assert_eq!(client.get_escrow(&1).status, EscrowStatus::Refunded);
+    assert_eq!(client.get_request(&1).status, RequestStatus::Expired);
+    assert_eq!(client.get_land_owner(&land_id), seller);
+}
+
+#[test]
+fn test_refund_escrow_before_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &20000);
+
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+
+    // Should fail - inspector still has time to finalize
+    let result = client.try_refund_escrow(&buyer, &1);
+    assert_eq!(result, Err(Ok(RegistryError::EscrowDeadlineNotReached)));
+}
+
+#[test]
+fn test_cannot_transfer_after_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &20000);
+
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+
+    env.ledger().set_timestamp(client.get_escrow(&1).deadline);
+    client.refund_escrow(&buyer, &1);
+
+    // Should fail - funds already returned to the buyer
+    let result = client.try_transfer_ownership(&inspector, &1);
+    assert_eq!(result, Err(Ok(RegistryError::EscrowAlreadySettled)));
+}
+
+#[test]
+fn test_only_inspector_can_verify() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    let result = client.try_verify_land(&seller, &land_id);
+    assert_eq!(result, Err(Ok(RegistryError::NotInspector)));
+    assert!(!client.is_land_verified(&land_id));
+}
+
+#[test]
+fn test_payment_requires_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &20000);
+
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+
+    let result = client.try_payment(&buyer, &1);
+    assert_eq!(result, Err(Ok(RegistryError::RequestNotApproved)));
+    assert_eq!(client.try_get_request(&2).err(), Some(Ok(RegistryError::RequestNotFound)));
+}
+
+#[test]
+fn test_events_registration_and_listing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+
+    let seller = Address::generate(&env);
+    client.register_seller(
+        &seller,
+        &String::from_str(&env, "Seller"),
+        &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "abc"),
+        &String::from_str(&env, "xyz"),
+        &String::from_str(&env, "many"),
+        &String::from_str(&env, "QmHash")
+    );
+    assert_last_event(&env, &client.address, events::SellerRegistered { seller: seller.clone() });
+
+    client.verify_seller(&inspector, &seller);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::SellerVerified { seller: seller.clone(), inspector: inspector.clone() },
+    );
+
+    client.add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &567,
+        &1890,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    let land_id = 1;
+    assert_last_event(
+        &env,
+        &client.address,
+        events::LandAdded {
+            land_id,
+            owner: seller.clone(),
+            land_price: 20000,
+            is_fractional: false,
+            total_fractions: 0,
+        },
+    );
+
+    client.verify_land(&inspector, &land_id);
+    assert_last_event(&env, &client.address, events::LandVerified { land_id, inspector });
+}
+
+#[test]
+fn test_events_purchase_flow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &20000);
+
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::RequestCreated {
+            req_id: 1,
+            land_id,
+            buyer: buyer.clone(),
+            seller: seller.clone(),
+            is_fractional_purchase: false,
+        },
+    );
+
+    client.approve_request(&seller, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::RequestApproved { req_id: 1, land_id, seller: seller.clone() },
+    );
+
+    client.payment(&buyer, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::RequestPaid { req_id: 1, land_id, buyer: buyer.clone(), amount: 20000, escrowed: true },
+    );
+
+    client.transfer_ownership(&inspector, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::OwnershipTransferred {
+            land_id,
+            new_owner: buyer,
+            previous_owner: seller,
+            req_id: 1,
+            inspector,
+        },
+    );
+}
+
+#[test]
+fn test_entity_records_use_persistent_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().has(&DataKey::Land(land_id)));
+        assert!(env.storage().persistent().has(&DataKey::Party(seller.clone())));
+        assert!(!env.storage().instance().has(&DataKey::Land(land_id)));
+        assert!(!env.storage().instance().has(&DataKey::Party(seller.clone())));
+        assert!(env.storage().instance().has(&DataKey::LandCount));
+
+        assert_eq!(
+            env.storage().persistent().get_ttl(&DataKey::Land(land_id)),
+            storage::PERSISTENT_BUMP_AMOUNT
+        );
+    });
+}
+
+#[test]
+fn test_bump_land_and_party_extend_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    // Age the entries past the lifetime threshold
+    let elapsed = storage::PERSISTENT_BUMP_AMOUNT - storage::PERSISTENT_LIFETIME_THRESHOLD + 1;
+    env.ledger().set_sequence_number(env.ledger().sequence() + elapsed);
+
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().get_ttl(&DataKey::Land(land_id)) < storage::PERSISTENT_LIFETIME_THRESHOLD);
+    });
+
+    client.bump_land(&land_id);
+    client.bump_party(&seller);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            env.storage().persistent().get_ttl(&DataKey::Land(land_id)),
+            storage::PERSISTENT_BUMP_AMOUNT
+        );
+        assert_eq!(
+            env.storage().persistent().get_ttl(&DataKey::LandOwner(land_id)),
+            storage::PERSISTENT_BUMP_AMOUNT
+        );
+        assert_eq!(
+            env.storage().persistent().get_ttl(&DataKey::Party(seller.clone())),
+            storage::PERSISTENT_BUMP_AMOUNT
+        );
+    });
+
+    assert_eq!(client.try_bump_land(&99), Err(Ok(RegistryError::LandNotFound)));
+    assert_eq!(client.try_bump_party(&Address::generate(&env)), Err(Ok(RegistryError::PartyNotFound)));
+}
+
+#[test]
+fn test_inspector_roster() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+
+    let second = Address::generate(&env);
+    client.add_inspector(
+        &admin,
+        &second,
+        &String::from_str(&env, "Inspector 2"),
+        &38,
+        &String::from_str(&env, "Sub-Registrar"),
+        &state_wide(&env, "Maharashtra")
+    );
+    assert_last_event(
+        &env,
+        &client.address,
+        events::InspectorAdded { inspector: second.clone(), admin: admin.clone() },
+    );
+
+    let inspectors = client.list_inspectors();
+    assert_eq!(inspectors.len(), 2);
+    assert_eq!(inspectors.get(0).unwrap().address, inspector);
+    assert_eq!(inspectors.get(1).unwrap().id, 2);
+    assert_eq!(inspectors.get(1).unwrap().jurisdictions, state_wide(&env, "Maharashtra"));
+    assert!(client.is_land_inspector(&second));
+
+    // Both inspectors can act on the registry
+    let seller = register_verified_seller(&env, &client, &second);
+    assert!(client.get_seller(&seller).verified);
+
+    client.remove_inspector(&admin, &second);
+    assert!(!client.is_land_inspector(&second));
+    assert_eq!(client.list_inspectors().len(), 1);
+    assert_eq!(client.try_get_inspector(&second).err(), Some(Ok(RegistryError::InspectorNotFound)));
+
+    let result = client.try_verify_seller(&second, &seller);
+    assert_eq!(result, Err(Ok(RegistryError::NotInspector)));
+}
+
+#[test]
+fn test_only_admin_manages_inspectors() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_add_inspector(
+        &inspector,
+        &outsider,
+        &String::from_str(&env, "Inspector 2"),
+        &38,
+        &String::from_str(&env, "Sub-Registrar"),
+        &state_wide(&env, "Maharashtra")
+    );
+    assert_eq!(result, Err(Ok(RegistryError::NotAdmin)));
+
+    let result = client.try_remove_inspector(&outsider, &inspector);
+    assert_eq!(result, Err(Ok(RegistryError::NotAdmin)));
+
+    let result = client.try_add_inspector(
+        &client.get_admin(),
+        &inspector,
+        &String::from_str(&env, "Inspector 1"),
+        &45,
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Maharashtra")
+    );
+    assert_eq!(result, Err(Ok(RegistryError::InspectorAlreadyExists)));
+}
+
+#[test]
+fn test_inspector_limited_to_jurisdiction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+
+    // Sub-registrar for a single city in another state
+    let outsider = Address::generate(&env);
+    client.add_inspector(
+        &admin,
+        &outsider,
+        &String::from_str(&env, "Inspector 2"),
+        &38,
+        &String::from_str(&env, "Sub-Registrar"),
+        &vec![&env, Jurisdiction {
+            state: String::from_str(&env, "Karnataka"),
+            city: String::from_str(&env, "Mysuru"),
+        }]
+    );
+
+    let seller = Address::generate(&env);
+    client.register_seller(
+        &seller,
+        &String::from_str(&env, "Seller"),
+        &30,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "abc"),
+        &String::from_str(&env, "xyz"),
+        &String::from_str(&env, "many"),
+        &String::from_str(&env, "QmHash")
+    );
+    let result = client.try_verify_seller(&outsider, &seller);
+    assert_eq!(result, Err(Ok(RegistryError::OutsideJurisdiction)));
+    let result = client.try_reject_seller(&outsider, &seller);
+    assert_eq!(result, Err(Ok(RegistryError::OutsideJurisdiction)));
+    client.verify_seller(&inspector, &seller);
+
+    let buyer = Address::generate(&env);
+    client.register_buyer(
+        &buyer,
+        &String::from_str(&env, "Buyer"),
+        &25,
+        &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "aadhar"),
+        &String::from_str(&env, "pan"),
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "buyer@email.com")
+    );
+    let result = client.try_verify_buyer(&outsider, &buyer);
+    assert_eq!(result, Err(Ok(RegistryError::OutsideJurisdiction)));
+    client.verify_buyer(&inspector, &buyer);
+
+    let land_id = add_sample_land(&env, &client, &seller);
+    let result = client.try_verify_land(&outsider, &land_id);
+    assert_eq!(result, Err(Ok(RegistryError::OutsideJurisdiction)));
+    assert!(!client.is_land_verified(&land_id));
+    client.verify_land(&inspector, &land_id);
+
+    token_admin.mint(&buyer, &20000);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+
+    let result = client.try_transfer_ownership(&outsider, &1);
+    assert_eq!(result, Err(Ok(RegistryError::OutsideJurisdiction)));
+    assert_eq!(client.get_land_owner(&land_id), seller);
+
+    client.transfer_ownership(&inspector, &1);
+    assert_eq!(client.get_land_owner(&land_id), buyer);
+    assert_eq!(token.balance(&seller), 20000);
+}
+
+#[test]
+fn test_assign_and_revoke_jurisdiction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    let akola = Jurisdiction {
+        state: String::from_str(&env, "Maharashtra"),
+        city: String::from_str(&env, "Akola"),
+    };
+
+    let second = Address::generate(&env);
+    client.add_inspector(
+        &admin,
+        &second,
+        &String::from_str(&env, "Inspector 2"),
+        &38,
+        &String::from_str(&env, "Sub-Registrar"),
+        &Vec::new(&env)
+    );
+    assert_eq!(client.try_verify_land(&second, &land_id), Err(Ok(RegistryError::OutsideJurisdiction)));
+
+    client.assign_jurisdiction(&admin, &second, &akola);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::JurisdictionAssigned {
+            inspector: second.clone(),
+            state: akola.state.clone(),
+            city: akola.city.clone(),
+        },
+    );
+    // Assigning twice does not duplicate the entry
+    client.assign_jurisdiction(&admin, &second, &akola);
+    assert_eq!(client.get_inspector(&second).jurisdictions, vec![&env, akola.clone()]);
+
+    client.verify_land(&second, &land_id);
+    assert!(client.is_land_verified(&land_id));
+
+    client.revoke_jurisdiction(&admin, &second, &akola);
+    assert_eq!(client.get_inspector(&second).jurisdictions.len(), 0);
+    assert_eq!(client.try_verify_land(&second, &land_id), Err(Ok(RegistryError::OutsideJurisdiction)));
+
+    let result = client.try_assign_jurisdiction(&inspector, &second, &akola);
+    assert_eq!(result, Err(Ok(RegistryError::NotAdmin)));
+    let result = client.try_assign_jurisdiction(&admin, &Address::generate(&env), &akola);
+    assert_eq!(result, Err(Ok(RegistryError::InspectorNotFound)));
+}
+
+// Deploys the pinned v1 release with an inspector and a verified seller
+fn setup_v1_contract<'a>(
+    env: &Env,
+) -> (land_registry_v1::Client<'a>, Address, Address, Address, token::StellarAssetClient<'a>) {
+    let contract_id = env.register(land_registry_v1::WASM, ());
+    let v1 = land_registry_v1::Client::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let inspector = Address::generate(env);
+    let (token, token_admin) = create_token(env);
+    v1.initialize(&admin, &token.address);
+    v1.add_inspector(
+        &admin,
+        &inspector,
+        &String::from_str(env, "Inspector 1"),
+        &45,
+        &String::from_str(env, "Tehsil Manager"),
+        &vec![env, land_registry_v1::Jurisdiction {
+            state: String::from_str(env, "Maharashtra"),
+            city: String::from_str(env, "*"),
+        }]
+    );
+    let seller = Address::generate(env);
+    v1.register_seller(
+        &seller,
+        &String::from_str(env, "Seller"),
+        &30,
+        &String::from_str(env, "Akola"),
+        &String::from_str(env, "Maharashtra"),
+        &String::from_str(env, "abc"),
+        &String::from_str(env, "xyz"),
+        &String::from_str(env, "many"),
+        &String::from_str(env, "QmHash")
+    );
+    v1.verify_seller(&inspector, &seller);
+    (v1, admin, inspector, seller, token_admin)
+}
+
+#[test]
+fn test_upgrade_keeps_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (v1, admin, inspector, seller, token_admin) = setup_v1_contract(&env);
+    let buyer = Address::generate(&env);
+    v1.register_buyer(
+        &buyer,
+        &String::from_str(&env, "Buyer"),
+        &25,
+        &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "aadhar"),
+        &String::from_str(&env, "pan"),
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "buyer@email.com")
+    );
+    v1.verify_buyer(&inspector, &buyer);
+    v1.add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &567,
+        &1890,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    v1.verify_land(&inspector, &1);
+    token_admin.mint(&buyer, &20000);
+    v1.request_land(&buyer, &seller, &1);
+    v1.approve_request(&seller, &1);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(land_registry_v2::WASM);
+    v1.upgrade(&admin, &wasm_hash);
+    assert_last_event(
+        &env,
+        &v1.address,
+        events::ContractUpgraded { admin: admin.clone(), wasm_hash },
+    );
+
+    // Same address, now served by the uploaded wasm
+    let v2 = land_registry_v2::Client::new(&env, &v1.address);
+    assert_eq!(v2.migrate(&admin), 2);
+    assert_eq!(v2.get_land(&1).land_price, 20000);
+    assert_eq!(v2.get_land_owner(&1), seller);
+    assert!(v2.get_request(&1).approved);
+
+    // The purchase started before the upgrade completes on the new code
+    v2.payment(&buyer, &1);
+    v2.transfer_ownership(&inspector, &1);
+    assert_eq!(v2.get_land_owner(&1), buyer);
+}
+
+#[test]
+fn test_migrate_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    add_small_fractional_land(&env, &client, &seller, 4);
+    client.verify_land(&inspector, &land_id);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &7u32);
+        env.storage().persistent().remove(&DataKey::LandListing(land_id));
+    });
+
+    // One land per call: the v7 step stays open and the version is not stamped yet
+    assert_eq!(client.migrate(&admin, &1), 7);
+    assert_eq!(client.get_land_listing(&land_id).price, 20000);
+    assert_eq!(client.get_schema_version(), 7);
+
+    // Nothing may change records while they are in mixed layouts
+    assert_eq!(client.try_request_land(&buyer, &land_id), Err(Ok(RegistryError::UnsupportedSchemaVersion)));
+
+    // The remaining land finishes v7; the rest of the limit starts on v8
+    assert_eq!(client.migrate(&admin, &2), 8);
+    assert_last_event(&env, &client.address, events::SchemaMigrated { from_version: 7, to_version: 8 });
+    assert_eq!(client.migrate(&admin, &10), SCHEMA_VERSION);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::SchemaMigrated { from_version: SCHEMA_VERSION - 1, to_version: SCHEMA_VERSION },
+    );
+    client.request_land(&buyer, &land_id);
+
+    // Running it again is a no-op
+    assert_eq!(client.migrate(&admin, &10), SCHEMA_VERSION);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &(SCHEMA_VERSION + 1));
+    });
+    assert_eq!(client.try_migrate(&admin, &10), Err(Ok(RegistryError::UnsupportedSchemaVersion)));
+}
+
+#[test]
+fn test_migrate_v1_title_index_and_request_flags() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+
+    // v1 had no title index, no listings and stored request state as two flags
+    env.as_contract(&client.address, || {
+        let land: LandReg = env.storage().persistent().get(&DataKey::Land(land_id)).unwrap();
+        env.storage().instance().set(&DataKey::SchemaVersion, &1u32);
+        env.storage().persistent().remove(&DataKey::PropertyPid(567));
+        env.storage().persistent().remove(&LandRegistryContract::survey_key(&land));
+        env.storage().persistent().remove(&DataKey::LandListing(land_id));
+        env.storage().instance().set(&DataKey::RequestCount, &3u32);
+        for (req_id, approved, payment_received) in [(1, false, false), (2, true, false), (3, true, true)] {
+            let request = migration::LandRequestV2 {
+                req_id,
+                seller_id: seller.clone(),
+                buyer_id: buyer.clone(),
+                land_id,
+                approved,
+                payment_received,
+                is_fractional_purchase: false,
+                fraction_id: None,
+            };
+            env.storage().persistent().set(&DataKey::Request(req_id), &request);
+        }
+    });
+
+    // The single land finishes v1 and the rest of the batch starts on the requests
+    env.ledger().set_timestamp(500);
+    assert_eq!(client.migrate(&admin, &2), 2);
+    assert_eq!(client.migrate(&admin, &500), SCHEMA_VERSION);
+
+    let statuses = [RequestStatus::Pending, RequestStatus::Approved, RequestStatus::Paid];
+    for (req_id, status) in (1..=3).zip(statuses) {
+        let request = client.get_request(&req_id);
+        assert_eq!((request.status, request.created_at, request.updated_at), (status, 500, 500));
+    }
+    assert_eq!((client.get_request(&1).price, client.get_request(&2).price), (0, 20000));
+    assert_eq!(client.get_land_listing(&land_id).pending_sale, Some(3));
+
+    // The rebuilt index rejects duplicates again
+    let result = client.try_add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Pune"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &567,
+        &42,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    assert_eq!(result, Err(Ok(RegistryError::DuplicatePropertyPid)));
+    let result = client.try_add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &568,
+        &1890,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    assert_eq!(result, Err(Ok(RegistryError::DuplicateSurveyNumber)));
+}
+
+#[test]
+fn test_migrate_v3_timestamps_requests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &3u32);
+        env.storage().instance().set(&DataKey::RequestCount, &2u32);
+        for (req_id, status) in [(1, RequestStatus::Approved), (2, RequestStatus::Rejected)] {
+            let request = migration::LandRequestV3 {
+                req_id,
+                seller_id: seller.clone(),
+                buyer_id: buyer.clone(),
+                land_id,
+                status,
+                is_fractional_purchase: false,
+                fraction_id: None,
+            };
+            env.storage().persistent().set(&DataKey::Request(req_id), &request);
+        }
+    });
+
+    // Open requests get a full expiry window from the migration
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.migrate(&admin, &1), 3);
+    assert_eq!(client.migrate(&admin, &500), SCHEMA_VERSION);
+    let approved = client.get_request(&1);
+    assert_eq!((approved.status, approved.created_at, approved.updated_at), (RequestStatus::Approved, 1_000, 1_000));
+    assert_eq!(approved.price, 20000);
+    let rejected = client.get_request(&2);
+    assert_eq!((rejected.status, rejected.price), (RequestStatus::Rejected, 0));
+}
+
+#[test]
+fn test_only_admin_upgrades_and_migrates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let wasm_hash = env.deployer().upload_contract_wasm(land_registry_v2::WASM);
+
+    assert_eq!(client.try_upgrade(&inspector, &wasm_hash), Err(Ok(RegistryError::NotAdmin)));
+    assert_eq!(client.try_migrate(&inspector, &500), Err(Ok(RegistryError::NotAdmin)));
+}
+
+#[test]
+fn test_admin_transfer_two_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let new_admin = Address::generate(&env);
+
+    assert_eq!(client.try_accept_admin_transfer(&new_admin), Err(Ok(RegistryError::NoPendingTransfer)));
+    assert_eq!(
+        client.try_propose_admin_transfer(&inspector, &new_admin),
+        Err(Ok(RegistryError::NotAdmin))
+    );
+
+    client.propose_admin_transfer(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin_transfer().to, new_admin);
+    // Nothing changes until the new key accepts
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(
+        client.try_accept_admin_transfer(&inspector),
+        Err(Ok(RegistryError::NotPendingRecipient))
+    );
+
+    client.accept_admin_transfer(&new_admin);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::AdminTransferred { new_admin: new_admin.clone(), previous_admin: admin.clone() },
+    );
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.try_get_pending_admin_transfer().err(), Some(Ok(RegistryError::NoPendingTransfer)));
+
+    // The old key has lost its powers
+    assert_eq!(client.try_set_escrow_period(&admin, &60), Err(Ok(RegistryError::NotAdmin)));
+    client.set_escrow_period(&new_admin, &60);
+
+    // A proposal can be withdrawn before it is accepted
+    let other = Address::generate(&env);
+    client.propose_admin_transfer(&new_admin, &other);
+    client.cancel_admin_transfer(&new_admin);
+    assert_eq!(client.try_accept_admin_transfer(&other), Err(Ok(RegistryError::NoPendingTransfer)));
+    assert_eq!(client.try_cancel_admin_transfer(&new_admin), Err(Ok(RegistryError::NoPendingTransfer)));
+}
+
+#[test]
+fn test_inspector_transfer_two_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let new_key = Address::generate(&env);
+
+    client.propose_inspector_transfer(&inspector, &inspector, &new_key);
+    assert_eq!(client.get_pending_inspector_transfer(&inspector).to, new_key);
+    assert!(client.is_land_inspector(&inspector));
+    assert!(!client.is_land_inspector(&new_key));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_accept_inspector_transfer(&stranger, &inspector),
+        Err(Ok(RegistryError::NotPendingRecipient))
+    );
+
+    client.accept_inspector_transfer(&new_key, &inspector);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::InspectorTransferred { inspector: inspector.clone(), new_inspector: new_key.clone() },
+    );
+
+    // The seat moves with its id and jurisdictions
+    let record = client.get_inspector(&new_key);
+    assert_eq!(record.id, 1);
+    assert_eq!(record.jurisdictions, state_wide(&env, "Maharashtra"));
+    assert!(!client.is_land_inspector(&inspector));
+    assert_eq!(client.list_inspectors().get(0).unwrap().address, new_key);
+    assert_eq!(
+        client.try_get_pending_inspector_transfer(&inspector).err(),
+        Some(Ok(RegistryError::NoPendingTransfer))
+    );
+
+    let seller = register_verified_seller(&env, &client, &new_key);
+    assert!(client.get_seller(&seller).verified);
+    let land_id = add_sample_land(&env, &client, &seller);
+    assert_eq!(client.try_verify_land(&inspector, &land_id), Err(Ok(RegistryError::NotInspector)));
+
+    // Lost key: the admin proposes on the inspector's behalf, then cancels
+    let replacement = Address::generate(&env);
+    client.propose_inspector_transfer(&admin, &new_key, &replacement);
+    client.cancel_inspector_transfer(&admin, &new_key);
+    assert_eq!(
+        client.try_accept_inspector_transfer(&replacement, &new_key),
+        Err(Ok(RegistryError::NoPendingTransfer))
+    );
+}
+
+#[test]
+fn test_inspector_transfer_guards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+
+    let second = Address::generate(&env);
+    client.add_inspector(
+        &admin,
+        &second,
+        &String::from_str(&env, "Inspector 2"),
+        &38,
+        &String::from_str(&env, "Sub-Registrar"),
+        &state_wide(&env, "Maharashtra")
+    );
+
+    // Another inspector cannot rotate someone else's seat
+    let result = client.try_propose_inspector_transfer(&second, &inspector, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(RegistryError::NotAdmin)));
+
+    // Cannot hand a seat to an address that already holds one
+    let result = client.try_propose_inspector_transfer(&inspector, &inspector, &second);
+    assert_eq!(result, Err(Ok(RegistryError::InspectorAlreadyExists)));
+
+    let result = client.try_propose_inspector_transfer(&admin, &Address::generate(&env), &second);
+    assert_eq!(result, Err(Ok(RegistryError::InspectorNotFound)));
+
+    // Removing the inspector drops any pending handover
+    let new_key = Address::generate(&env);
+    client.propose_inspector_transfer(&inspector, &inspector, &new_key);
+    client.remove_inspector(&admin, &inspector);
+    assert_eq!(
+        client.try_accept_inspector_transfer(&new_key, &inspector),
+        Err(Ok(RegistryError::NoPendingTransfer))
+    );
+}
+
+#[test]
+fn test_pause_scopes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&buyer, &20000);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+
+    let all = vec![
+        &env,
+        PauseScope::Registrations,
+        PauseScope::LandListing,
+        PauseScope::Requests,
+        PauseScope::Payments,
+        PauseScope::Transfers,
+    ];
+    client.pause(&admin, &all);
+    assert_last_event(&env, &client.address, events::Paused { admin: admin.clone(), scopes: all.clone() });
+    for scope in all.iter() {
+        assert!(client.is_paused(&scope));
+    }
+
+    let paused = Err(Ok(RegistryError::ContractPaused));
+    let result = client.try_register_buyer(
+        &Address::generate(&env),
+        &String::from_str(&env, "Buyer"),
+        &25,
+        &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "aadhar"),
+        &String::from_str(&env, "pan"),
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "buyer@email.com")
+    );
+    assert_eq!(result, paused);
+    let result = client.try_add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &568,
+        &1891,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    assert_eq!(result, paused);
+    assert_eq!(client.try_verify_land(&inspector, &land_id), paused);
+    assert_eq!(client.try_request_land(&buyer, &land_id), paused);
+    assert_eq!(client.try_approve_request(&seller, &1), paused);
+    assert_eq!(client.try_payment(&buyer, &1), paused);
+    assert_eq!(client.try_transfer_ownership(&inspector, &1), paused);
+    assert_eq!(client.try_refund_escrow(&buyer, &1), paused);
+
+    // Getters keep working while paused
+    assert_eq!(client.get_land(&land_id).land_price, 20000);
+    assert_eq!(client.get_request(&1).status, RequestStatus::Approved);
+    assert_eq!(client.get_land_owner(&land_id), seller);
+
+    // Scopes are lifted independently
+    client.unpause(&admin, &vec![&env, PauseScope::Payments]);
+    assert!(!client.is_paused(&PauseScope::Payments));
+    client.payment(&buyer, &1);
+    assert_eq!(client.try_transfer_ownership(&inspector, &1), paused);
+
+    client.unpause(&admin, &vec![&env, PauseScope::Transfers]);
+    client.transfer_ownership(&inspector, &1);
+    assert_eq!(client.get_land_owner(&land_id), buyer);
+    assert!(client.is_paused(&PauseScope::Registrations));
+}
+
+#[test]
+fn test_only_admin_pauses() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let scopes = vec![&env, PauseScope::Payments];
+
+    assert_eq!(client.try_pause(&inspector, &scopes), Err(Ok(RegistryError::NotAdmin)));
+    client.pause(&client.get_admin(), &scopes);
+    assert_eq!(client.try_unpause(&inspector, &scopes), Err(Ok(RegistryError::NotAdmin)));
+    assert!(client.is_paused(&PauseScope::Payments));
+}
+
+#[test]
+fn test_upgrade_v1_builds_title_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // A v1 deployment, which did not reject duplicate titles
+    let (v1, admin, _, seller, _) = setup_v1_contract(&env);
+    let contract_id = v1.address.clone();
+    for _ in 0..2 {
+        v1.add_land(
+            &seller,
+            &500,
+            &String::from_str(&env, "Akola"),
+            &String::from_str(&env, "Maharashtra"),
+            &20000,
+            &567,
+            &1890,
+            &String::from_str(&env, "QmHash"),
+            &String::from_str(&env, "QmHash")
+        );
+    }
+    assert_eq!(v1.get_lands_count(), 2);
+    assert_eq!(v1.get_schema_version(), 1);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(land_registry_v2::WASM);
+    v1.upgrade(&admin, &wasm_hash);
+
+    let v2 = land_registry_v2::Client::new(&env, &contract_id);
+    assert_eq!(v2.try_get_land_by_pid(&567).err(), Some(Ok(land_registry_v2::RegistryError::LandNotFound)));
+    assert_eq!(v2.migrate(&admin), 2);
+    assert_eq!(v2.get_schema_version(), 2);
+
+    // The earlier registration keeps the index entry
+    assert_eq!(v2.get_land_by_pid(&567).id, 1);
+    let by_survey = v2.get_land_by_survey(
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "Akola"),
+        &1890,
+    );
+    assert_eq!(by_survey.id, 1);
+    assert_eq!(v2.get_land(&2).property_pid, 567);
+
+    // New duplicates are now rejected
+    let result = v2.try_add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &567,
+        &1999,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    assert_eq!(result.err(), Some(Ok(land_registry_v2::RegistryError::DuplicatePropertyPid)));
+}
+
+#[test]
+fn test_land_price_must_cover_every_fraction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+
+    let result = client.try_add_land(
+        &seller,
+        &500,
+        &String::from_str(&env, "Pune"),
+        &String::from_str(&env, "Maharashtra"),
+        &0,
+        &567,
+        &42,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    assert_eq!(result, Err(Ok(RegistryError::InvalidPrice)));
+
+    // Below one token per fraction, price_per_fraction would round down to nothing
+    for total_price in [-10, 9] {
+        let result = client.try_add_fractional_land(
+            &seller,
+            &500,
+            &String::from_str(&env, "Pune"),
+            &String::from_str(&env, "Maharashtra"),
+            &total_price,
+            &567,
+            &42,
+            &String::from_str(&env, "QmHash"),
+            &String::from_str(&env, "QmHash"),
+            &10
+        );
+        assert_eq!(result, Err(Ok(RegistryError::InvalidPrice)));
+    }
+    assert_eq!(client.get_lands_count(), 0);
+
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    assert_eq!(
+        client.try_fractionalize_land(&seller, &land_id, &10, &9, &0),
+        Err(Ok(RegistryError::InvalidPrice))
+    );
+    client.fractionalize_land(&seller, &land_id, &10, &10, &0);
+}
+
+#[test]
+fn test_duplicate_titles_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let other_seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    // Same PID from a different seller
+    let result = client.try_add_land(
+        &other_seller,
+        &500,
+        &String::from_str(&env, "Pune"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &567,
+        &42,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+    assert_eq!(result, Err(Ok(RegistryError::DuplicatePropertyPid)));
+
+    // Same survey number in the same city, listed as fractional
+    let result = client.try_add_fractional_land(
+        &other_seller,
+        &500,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &999,
+        &1890,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash"),
+        &10
+    );
+    assert_eq!(result, Err(Ok(RegistryError::DuplicateSurveyNumber)));
+    assert_eq!(client.get_lands_count(), 1);
+
+    // The same survey number in another city is a different parcel
+    client.add_land(
+        &other_seller,
+        &500,
+        &String::from_str(&env, "Pune"),
+        &String::from_str(&env, "Maharashtra"),
+        &20000,
+        &999,
+        &1890,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash")
+    );
+
+    assert_eq!(client.get_land_by_pid(&567).id, land_id);
+    assert_eq!(client.get_land_by_pid(&999).id, 2);
+    let land = client.get_land_by_survey(
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "Pune"),
+        &1890,
+    );
+    assert_eq!(land.id, 2);
+    assert_eq!(client.try_get_land_by_pid(&1).err(), Some(Ok(RegistryError::LandNotFound)));
+}
+
+#[test]
+fn test_cancel_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &40000);
+
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    assert_eq!(client.try_cancel_request(&seller, &1), Err(Ok(RegistryError::NotRequestBuyer)));
+
+    client.cancel_request(&buyer, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::RequestCancelled { req_id: 1, land_id, buyer: buyer.clone() },
+    );
+    assert_eq!(client.get_request(&1).status, RequestStatus::Cancelled);
+
+    // A cancelled request cannot be revived
+    assert_eq!(client.try_approve_request(&seller, &1), Err(Ok(RegistryError::InvalidRequestStatus)));
+    assert_eq!(client.try_payment(&buyer, &1), Err(Ok(RegistryError::InvalidRequestStatus)));
+    assert_eq!(client.try_cancel_request(&buyer, &1), Err(Ok(RegistryError::InvalidRequestStatus)));
+
+    // Approved but unpaid requests can still be withdrawn; paid ones cannot
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &2);
+    client.cancel_request(&buyer, &2);
+    assert_eq!(client.get_request(&2).status, RequestStatus::Cancelled);
+
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &3);
+    client.payment(&buyer, &3);
+    assert_eq!(client.try_cancel_request(&buyer, &3), Err(Ok(RegistryError::PaymentAlreadyReceived)));
+}
+
+#[test]
+fn test_reject_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    assert_eq!(client.try_reject_request(&buyer, &1), Err(Ok(RegistryError::NotRequestSeller)));
+
+    client.reject_request(&seller, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::RequestRejected { req_id: 1, land_id, seller: seller.clone() },
+    );
+    assert_eq!(client.get_request(&1).status, RequestStatus::Rejected);
+    assert_eq!(client.try_approve_request(&seller, &1), Err(Ok(RegistryError::InvalidRequestStatus)));
+    assert_eq!(client.try_cancel_request(&buyer, &1), Err(Ok(RegistryError::InvalidRequestStatus)));
+
+    // The seller may change their mind after approving, until payment arrives
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &2);
+    assert_eq!(client.try_approve_request(&seller, &2), Err(Ok(RegistryError::InvalidRequestStatus)));
+    client.reject_request(&seller, &2);
+    assert_eq!(client.try_payment(&buyer, &2), Err(Ok(RegistryError::InvalidRequestStatus)));
+    assert_eq!(client.try_reject_request(&seller, &99), Err(Ok(RegistryError::RequestNotFound)));
+}
+
+#[test]
+fn test_request_lifecycle_timestamps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&buyer, &20000);
+
+    env.ledger().set_timestamp(1_000);
+    client.request_land(&buyer, &land_id);
+    let request = client.get_request(&1);
+    assert_eq!(request.status, RequestStatus::Pending);
+    assert_eq!((request.created_at, request.updated_at), (1_000, 1_000));
+
+    env.ledger().set_timestamp(2_000);
+    client.approve_request(&seller, &1);
+    let request = client.get_request(&1);
+    assert_eq!((request.created_at, request.updated_at), (1_000, 2_000));
+
+    env.ledger().set_timestamp(3_000);
+    client.payment(&buyer, &1);
+    assert_eq!(client.get_request(&1).status, RequestStatus::Paid);
+    assert_eq!(client.try_payment(&buyer, &1), Err(Ok(RegistryError::PaymentAlreadyReceived)));
+
+    env.ledger().set_timestamp(4_000);
+    client.transfer_ownership(&inspector, &1);
+    let request = client.get_request(&1);
+    assert_eq!(request.status, RequestStatus::Completed);
+    assert_eq!(request.updated_at, 4_000);
+    assert_eq!(client.try_cancel_request(&buyer, &1), Err(Ok(RegistryError::PaymentAlreadyReceived)));
+}
+
+#[test]
+fn test_fractional_request_completes_on_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    client.add_fractional_land(
+        &seller,
+        &1000,
+        &String::from_str(&env, "Akola"),
+        &String::from_str(&env, "Maharashtra"),
+        &100000,
+        &777,
+        &2024,
+        &String::from_str(&env, "QmHash"),
+        &String::from_str(&env, "QmHash"),
+        &10
+    );
+    token_admin.mint(&buyer, &10000);
+
+    client.verify_land(&inspector, &1);
+    client.request_fractional_land(&buyer, &1, &1);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+    assert_eq!(client.get_request(&1).status, RequestStatus::Completed);
+    assert_eq!(client.try_payment(&buyer, &1), Err(Ok(RegistryError::PaymentAlreadyReceived)));
+}
+
+#[test]
+fn test_request_expiry_windows() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &20000);
+
+    client.set_request_windows(&admin, &100, &50);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::RequestWindowsSet { admin: admin.clone(), approval_window: 100, payment_window: 50 },
+    );
+    assert_eq!(client.get_request_windows(), (100, 50));
+    assert_eq!(client.try_set_request_windows(&seller, &1, &1), Err(Ok(RegistryError::NotAdmin)));
+
+    // Seller misses the approval window
+    env.ledger().set_timestamp(1_000);
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+    assert_eq!(client.try_expire_request(&1), Err(Ok(RegistryError::RequestNotExpired)));
+    env.ledger().set_timestamp(1_101);
+    assert_eq!(client.try_approve_request(&seller, &1), Err(Ok(RegistryError::RequestExpired)));
+    client.expire_request(&1);
+    assert_last_event(&env, &client.address, events::RequestExpired { req_id: 1, land_id });
+    assert_eq!(client.get_request(&1).status, RequestStatus::Expired);
+    assert_eq!(client.try_expire_request(&1), Err(Ok(RegistryError::InvalidRequestStatus)));
+
+    // Buyer misses the payment window, which runs from approval
+    client.request_land(&buyer, &land_id);
+    env.ledger().set_timestamp(1_200);
+    client.approve_request(&seller, &2);
+    env.ledger().set_timestamp(1_251);
+    assert_eq!(client.try_payment(&buyer, &2), Err(Ok(RegistryError::RequestExpired)));
+    client.expire_request(&2);
+    assert_eq!(client.try_payment(&buyer, &2), Err(Ok(RegistryError::InvalidRequestStatus)));
+
+    // A zero window never expires
+    client.set_request_windows(&admin, &0, &0);
+    client.request_land(&buyer, &land_id);
+    env.ledger().set_timestamp(1_000_000);
+    client.approve_request(&seller, &3);
+    env.ledger().set_timestamp(2_000_000);
+    client.payment(&buyer, &3);
+    assert_eq!(client.get_request(&3).status, RequestStatus::Paid);
+}
+
+#[test]
+fn test_requests_follow_current_verified_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let other_buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &20000);
+    token_admin.mint(&other_buyer, &20000);
+
+    assert_eq!(client.try_request_land(&buyer, &land_id), Err(Ok(RegistryError::LandNotVerified)));
+    client.verify_land(&inspector, &land_id);
+
+    // The seller is taken from the title, not from the caller
+    client.request_land(&buyer, &land_id);
+    client.request_land(&other_buyer, &land_id);
+    assert_eq!(client.get_request(&1).seller_id, seller);
+    client.approve_request(&seller, &1);
+    client.approve_request(&seller, &2);
+
+    client.payment(&buyer, &1);
+    client.transfer_ownership(&inspector, &1);
+    assert_eq!(client.get_land_owner(&land_id), buyer);
+
+    // The competing request still names the old owner and can no longer settle
+    assert_eq!(client.try_payment(&other_buyer, &2), Err(Ok(RegistryError::SellerNotOwner)));
+
+    // The new owner has to list the land before it can be requested again
+    assert_eq!(client.try_request_land(&other_buyer, &land_id), Err(Ok(RegistryError::LandNotForSale)));
+    add_seller_role(&env, &client, &inspector, &buyer);
+    client.list_land_for_sale(&buyer, &land_id, &25000);
+
+    client.request_land(&other_buyer, &land_id);
+    assert_eq!(client.get_request(&3).seller_id, buyer);
+    assert_eq!(client.try_approve_request(&seller, &3), Err(Ok(RegistryError::NotRequestSeller)));
+}
+
+#[test]
+fn test_stale_request_cannot_be_approved() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let other_buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&buyer, &20000);
+
+    client.request_land(&buyer, &land_id);
+    client.request_land(&other_buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+    client.transfer_ownership(&inspector, &1);
+
+    assert_eq!(client.try_approve_request(&seller, &2), Err(Ok(RegistryError::SellerNotOwner)));
+}
+
+#[test]
+fn test_interleaved_fractional_requests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer1 = register_verified_buyer(&env, &client, &inspector);
+    let buyer2 = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_small_fractional_land(&env, &client, &seller, 2);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&buyer1, &100000);
+    token_admin.mint(&buyer2, &100000);
+
+    // Both requests are open before either is paid
+    client.request_fractional_land(&buyer1, &land_id, &1);
+    client.request_fractional_land(&buyer2, &land_id, &1);
+    client.approve_request(&seller, &1);
+    client.approve_request(&seller, &2);
+    assert_eq!(client.get_reserved_fractions(&land_id), 2);
+    assert_eq!(client.get_available_fractions(&land_id), 0);
+
+    // Paying in reverse order still issues distinct fractions
+    client.payment(&buyer2, &2);
+    client.payment(&buyer1, &1);
+    assert_eq!(client.get_request(&2).fraction_id, Some(1));
+    assert_eq!(client.get_request(&1).fraction_id, Some(2));
+    assert_eq!(client.get_fractional_ownership(&land_id, &1).owner, buyer2);
+    assert_eq!(client.get_fractional_ownership(&land_id, &2).owner, buyer1);
+    assert_eq!(client.get_reserved_fractions(&land_id), 0);
+    assert_eq!(client.get_available_fractions(&land_id), 0);
+    assert_eq!(client.get_land(&land_id).fractions_sold, 2);
+}
+
+#[test]
+fn test_fraction_reservations_block_and_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer1 = register_verified_buyer(&env, &client, &inspector);
+    let buyer2 = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_small_fractional_land(&env, &client, &seller, 1);
+    client.verify_land(&inspector, &land_id);
+    client.set_request_windows(&admin, &0, &100);
+
+    // Pending requests do not hold a fraction, approval does
+    client.request_fractional_land(&buyer1, &land_id, &1);
+    client.request_fractional_land(&buyer2, &land_id, &1);
+    client.approve_request(&seller, &1);
+    assert_eq!(client.try_approve_request(&seller, &2), Err(Ok(RegistryError::AllFractionsSold)));
+    assert_eq!(
+        client.try_request_fractional_land(&buyer2, &land_id, &1),
+        Err(Ok(RegistryError::AllFractionsSold))
+    );
+
+    // Cancelling releases the fraction
+    client.cancel_request(&buyer1, &1);
+    assert_eq!(client.get_reserved_fractions(&land_id), 0);
+    assert_eq!(client.get_available_fractions(&land_id), 1);
+
+    // Rejecting an approved request releases it too
+    client.approve_request(&seller, &2);
+    client.reject_request(&seller, &2);
+    assert_eq!(client.get_reserved_fractions(&land_id), 0);
+
+    // So does an approval that lapses unpaid
+    env.ledger().set_timestamp(1_000);
+    client.request_fractional_land(&buyer1, &land_id, &1);
+    client.approve_request(&seller, &3);
+    assert_eq!(client.get_reserved_fractions(&land_id), 1);
+    env.ledger().set_timestamp(1_101);
+    client.expire_request(&3);
+    assert_eq!(client.get_reserved_fractions(&land_id), 0);
+    assert_eq!(client.get_available_fractions(&land_id), 1);
+}
+
+#[test]
+fn test_multi_fraction_purchase() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer1 = register_verified_buyer(&env, &client, &inspector);
+    let buyer2 = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_small_fractional_land(&env, &client, &seller, 3);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&buyer1, &100000);
+    token_admin.mint(&buyer2, &100000);
+
+    assert_eq!(
+        client.try_request_fractional_land(&buyer1, &land_id, &0),
+        Err(Ok(RegistryError::InvalidFractionCount))
+    );
+    assert_eq!(
+        client.try_request_fractional_land(&buyer1, &land_id, &4),
+        Err(Ok(RegistryError::AllFractionsSold))
+    );
+
+    client.request_fractional_land(&buyer1, &land_id, &2);
+    client.request_fractional_land(&buyer2, &land_id, &2);
+    client.approve_request(&seller, &1);
+    assert_eq!(client.get_reserved_fractions(&land_id), 2);
+    assert_eq!(client.try_approve_request(&seller, &2), Err(Ok(RegistryError::AllFractionsSold)));
+
+    client.payment(&buyer1, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::FractionIssued { land_id, owner: buyer1.clone(), first_fraction_id: 1, num_fractions: 2 },
+    );
+    let price_per_fraction = client.get_land(&land_id).price_per_fraction;
+    assert_eq!(token.balance(&buyer1), 100000 - 2 * price_per_fraction);
+    assert_eq!(client.get_request(&1).num_fractions, 2);
+    assert_eq!(client.get_fractional_ownership(&land_id, &1).owner, buyer1);
+    assert_eq!(client.get_fractional_ownership(&land_id, &2).owner, buyer1);
+
+    client.cancel_request(&buyer2, &2);
+    client.request_fractional_land(&buyer2, &land_id, &1);
+    client.approve_request(&seller, &3);
+    client.payment(&buyer2, &3);
+
+    // Exact counts: 2/3 and 1/3, nothing lost to rounding
+    let (held1, total) = client.get_holder_share(&land_id, &buyer1);
+    let (held2, _) = client.get_holder_share(&land_id, &buyer2);
+    assert_eq!((held1, held2, total), (2, 1, 3));
+    assert_eq!(client.get_holder_share(&land_id, &seller), (0, 3));
+    assert_eq!(client.get_available_fractions(&land_id), 0);
+}
+
+#[test]
+fn test_migrate_v4_fraction_holdings() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_small_fractional_land(&env, &client, &seller, 4);
+
+    // v4 stored a rounded percentage per fraction and single-fraction requests
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &4u32);
+        env.storage().instance().set(&DataKey::RequestCount, &1u32);
+        let request = migration::LandRequestV4 {
+            req_id: 1,
+            seller_id: seller.clone(),
+            buyer_id: buyer.clone(),
+            land_id,
+            status: RequestStatus::Completed,
+            is_fractional_purchase: true,
+            fraction_id: Some(1),
+            created_at: 0,
+            updated_at: 0,
+        };
+        env.storage().persistent().set(&DataKey::Request(1), &request);
+        let fraction = migration::FractionalOwnershipV4 {
+            land_id,
+            owner: buyer.clone(),
+            fraction_id: 1,
+            fraction_percentage: 25,
+            purchase_date: 0,
+        };
+        env.storage().persistent().set(&DataKey::FractionalOwnership(land_id, 1), &fraction);
+        let mut land: LandReg = env.storage().persistent().get(&DataKey::Land(land_id)).unwrap();
+        land.fractions_sold = 1;
+        env.storage().persistent().set(&DataKey::Land(land_id), &land);
+        env.storage().persistent().remove(&DataKey::HolderFractions(land_id, seller.clone()));
+        env.storage().persistent().set(&DataKey::LandFractionOwners(land_id), &vec![&env, buyer.clone()]);
+        env.storage().persistent().set(&DataKey::UserFractionalLands(seller.clone()), &Vec::<u32>::new(&env));
+    });
+
+    assert_eq!(client.migrate(&admin, &500), SCHEMA_VERSION);
+    assert_eq!(client.get_request(&1).num_fractions, 1);
+    assert_eq!(client.get_fractional_ownership(&land_id, &1).owner, buyer);
+    assert_eq!(client.get_holder_share(&land_id, &buyer), (1, 4));
+    assert_eq!(client.get_holder_share(&land_id, &seller), (3, 4));
+}
+
+#[test]
+fn test_fractions_recorded_as_runs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_small_fractional_land(&env, &client, &seller, 100);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&alice, &100000);
+    client.request_fractional_land(&alice, &land_id, &60);
+    client.approve_request(&seller, &1);
+
+    // A large purchase is one ownership record, well inside the per-transaction write limit
+    client.payment(&alice, &1);
+    assert!(env.cost_estimate().resources().write_entries < 20);
+    let run = client.get_fractional_ownership(&land_id, &60);
+    assert_eq!((run.owner.clone(), run.fraction_id, run.num_fractions), (alice.clone(), 1, 60));
+    assert_eq!(
+        client.try_get_fractional_ownership(&land_id, &61).err(),
+        Some(Ok(RegistryError::FractionalOwnershipNotFound))
+    );
+
+    // Moving one fraction splits the run around it
+    client.transfer_fraction(&alice, &land_id, &30, &bob);
+    let head = client.get_fractional_ownership(&land_id, &29);
+    assert_eq!((head.owner, head.fraction_id, head.num_fractions), (alice.clone(), 1, 29));
+    let moved = client.get_fractional_ownership(&land_id, &30);
+    assert_eq!((moved.owner, moved.fraction_id, moved.num_fractions), (bob.clone(), 30, 1));
+    let tail = client.get_fractional_ownership(&land_id, &31);
+    assert_eq!((tail.owner, tail.fraction_id, tail.num_fractions), (alice.clone(), 31, 30));
+    assert_eq!(client.get_holder_share(&land_id, &alice), (59, 100));
+
+    // Moving the first fraction of a run leaves the rest under a new start
+    client.transfer_fraction(&alice, &land_id, &31, &bob);
+    let tail = client.get_fractional_ownership(&land_id, &60);
+    assert_eq!((tail.owner, tail.fraction_id, tail.num_fractions), (alice, 32, 29));
+    assert_eq!(client.get_fractional_ownership(&land_id, &31).owner, bob);
+}
+
+#[test]
+fn test_fraction_secondary_market() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 2);
+    token_admin.mint(&bob, &50000);
+
+    assert_eq!(
+        client.try_list_fraction_for_sale(&bob, &land_id, &1, &30000),
+        Err(Ok(RegistryError::NotFractionOwner))
+    );
+    assert_eq!(
+        client.try_list_fraction_for_sale(&alice, &land_id, &1, &0),
+        Err(Ok(RegistryError::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_list_fraction_for_sale(&alice, &land_id, &3, &30000),
+        Err(Ok(RegistryError::FractionalOwnershipNotFound))
+    );
+    assert_eq!(
+        client.try_buy_listed_fraction(&bob, &land_id, &1),
+        Err(Ok(RegistryError::FractionNotListed))
+    );
+
+    client.list_fraction_for_sale(&alice, &land_id, &1, &30000);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::FractionListed { land_id, fraction_id: 1, seller: alice.clone(), price: 30000 },
+    );
+    assert_eq!(client.get_fraction_listing(&land_id, &1).price, 30000);
+
+    let alice_balance = token.balance(&alice);
+    client.buy_listed_fraction(&bob, &land_id, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::FractionTransferred { land_id, fraction_id: 1, from: alice.clone(), to: bob.clone(), price: 30000 },
+    );
+    assert_eq!(token.balance(&alice), alice_balance + 30000);
+    assert_eq!(token.balance(&bob), 20000);
+    assert_eq!(client.get_fractional_ownership(&land_id, &1).owner, bob);
+    assert_eq!(client.get_holder_share(&land_id, &alice), (1, 4));
+    assert_eq!(client.get_holder_share(&land_id, &bob), (1, 4));
+    assert_eq!(client.get_land_fraction_owners(&land_id), vec![&env, seller.clone(), alice.clone(), bob.clone()]);
+    assert_eq!(client.get_user_fractional_lands(&bob), vec![&env, land_id]);
+    assert!(client.try_get_fraction_listing(&land_id, &1).is_err());
+
+    // Handing over the last fraction drops the holder from both indexes
+    client.transfer_fraction(&alice, &land_id, &2, &bob);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::FractionTransferred { land_id, fraction_id: 2, from: alice.clone(), to: bob.clone(), price: 0 },
+    );
+    assert_eq!(client.get_holder_share(&land_id, &alice), (0, 4));
+    assert_eq!(client.get_holder_share(&land_id, &bob), (2, 4));
+    assert_eq!(client.get_land_fraction_owners(&land_id), vec![&env, seller.clone(), bob.clone()]);
+    assert_eq!(client.get_user_fractional_lands(&alice).len(), 0);
+    assert_eq!(
+        client.try_transfer_fraction(&alice, &land_id, &2, &bob),
+        Err(Ok(RegistryError::NotFractionOwner))
+    );
+    assert_eq!(
+        client.try_transfer_fraction(&bob, &land_id, &2, &seller),
+        Err(Ok(RegistryError::BuyerNotFound))
+    );
+}
+
+#[test]
+fn test_fraction_listing_cleared_on_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 1);
+
+    client.list_fraction_for_sale(&alice, &land_id, &1, &30000);
+    assert_eq!(
+        client.try_delist_fraction(&bob, &land_id, &1),
+        Err(Ok(RegistryError::NotFractionOwner))
+    );
+    client.delist_fraction(&alice, &land_id, &1);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::FractionDelisted { land_id, fraction_id: 1, seller: alice.clone() },
+    );
+    assert_eq!(
+        client.try_delist_fraction(&alice, &land_id, &1),
+        Err(Ok(RegistryError::FractionNotListed))
+    );
+
+    // A stale listing cannot be bought once the fraction has moved
+    client.list_fraction_for_sale(&alice, &land_id, &1, &30000);
+    client.transfer_fraction(&alice, &land_id, &1, &bob);
+    assert_eq!(
+        client.try_buy_listed_fraction(&alice, &land_id, &1),
+        Err(Ok(RegistryError::FractionNotListed))
+    );
+
+    let admin = client.get_admin();
+    client.pause(&admin, &vec![&env, PauseScope::Transfers]);
+    assert_eq!(
+        client.try_transfer_fraction(&bob, &land_id, &1, &alice),
+        Err(Ok(RegistryError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_income_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let tenant = Address::generate(&env);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 2);
+    token_admin.mint(&bob, &100000);
+    client.request_fractional_land(&bob, &land_id, &1);
+    client.approve_request(&seller, &2);
+    client.payment(&bob, &2);
+    token_admin.mint(&tenant, &8000);
+
+    assert_eq!(client.try_deposit_income(&tenant, &land_id, &0), Err(Ok(RegistryError::InvalidAmount)));
+    assert_eq!(client.try_claim_income(&alice, &land_id), Err(Ok(RegistryError::NothingToClaim)));
+
+    // 2/4 to alice, 1/4 to bob, and the unsold 1/4 to the listing owner
+    client.deposit_income(&tenant, &land_id, &4000);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::IncomeDeposited { land_id, from: tenant.clone(), amount: 4000 },
+    );
+    assert_eq!(token.balance(&client.address), 4000);
+    assert_eq!(client.get_claimable_income(&land_id, &alice), 2000);
+    assert_eq!(client.get_claimable_income(&land_id, &bob), 1000);
+    assert_eq!(client.get_claimable_income(&land_id, &seller), 1000);
+
+    // Income already earned stays with alice after she hands a fraction to bob
+    client.transfer_fraction(&alice, &land_id, &1, &bob);
+    client.deposit_income(&tenant, &land_id, &4000);
+    assert_eq!(client.get_claimable_income(&land_id, &alice), 3000);
+    assert_eq!(client.get_claimable_income(&land_id, &bob), 3000);
+    assert_eq!(client.get_claimable_income(&land_id, &seller), 2000);
+
+    let alice_balance = token.balance(&alice);
+    assert_eq!(client.claim_income(&alice, &land_id), 3000);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::IncomeClaimed { land_id, holder: alice.clone(), amount: 3000 },
+    );
+    assert_eq!(token.balance(&alice), alice_balance + 3000);
+    assert_eq!(client.try_claim_income(&alice, &land_id), Err(Ok(RegistryError::NothingToClaim)));
+
+    // A holder who left the land can still claim what they earned
+    client.transfer_fraction(&bob, &land_id, &1, &alice);
+    client.transfer_fraction(&bob, &land_id, &3, &alice);
+    assert_eq!(client.get_holder_share(&land_id, &bob), (0, 4));
+    assert_eq!(client.claim_income(&bob, &land_id), 3000);
+    assert_eq!(client.claim_income(&seller, &land_id), 2000);
+    assert_eq!(token.balance(&client.address), 0);
+
+    let whole_land = add_sample_land(&env, &client, &seller);
+    assert_eq!(
+        client.try_deposit_income(&tenant, &whole_land, &100),
+        Err(Ok(RegistryError::LandNotFractional))
+    );
+}
+
+#[test]
+fn test_governance_proposal_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 2);
+    token_admin.mint(&bob, &100000);
+    client.request_fractional_land(&bob, &land_id, &1);
+    client.approve_request(&seller, &2);
+    client.payment(&bob, &2);
+
+    assert_eq!(
+        client.try_create_proposal(&Address::generate(&env), &land_id, &ProposalAction::ChangePrice(30000)),
+        Err(Ok(GovernanceError::NoVotingPower))
+    );
+    assert_eq!(
+        client.try_create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(0)),
+        Err(Ok(GovernanceError::InvalidPrice))
+    );
+
+    env.ledger().set_timestamp(1_000);
+    let proposal_id = client.create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(30000));
+    assert_last_event(
+        &env,
+        &client.address,
+        events::ProposalCreated {
+            proposal_id,
+            land_id,
+            proposer: alice.clone(),
+            action: ProposalAction::ChangePrice(30000),
+        },
+    );
+    let proposal = client.get_proposal(&proposal_id);
+    // Only the three issued fractions vote
+    assert_eq!(proposal.eligible_fractions, 3);
+    assert_eq!(proposal.ends_at, 1_000 + 7 * 24 * 60 * 60);
+
+    // Votes are weighted by fractions held
+    assert_eq!(client.vote(&alice, &proposal_id, &true), 2);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::VoteCast { proposal_id, voter: alice.clone(), support: true, weight: 2 },
+    );
+    assert_eq!(client.vote(&bob, &proposal_id, &false), 1);
+    assert_eq!(client.try_vote(&alice, &proposal_id, &true), Err(Ok(GovernanceError::NoVotingPower)));
+    assert_eq!(client.try_execute_proposal(&proposal_id), Err(Ok(GovernanceError::VotingNotEnded)));
+
+    env.ledger().set_timestamp(proposal.ends_at);
+    assert_eq!(client.try_vote(&bob, &proposal_id, &true), Err(Ok(GovernanceError::VotingClosed)));
+    assert_eq!(client.execute_proposal(&proposal_id), ProposalStatus::Executed);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::ProposalExecuted { proposal_id, land_id, action: ProposalAction::ChangePrice(30000) },
+    );
+    let land = client.get_land(&land_id);
+    assert_eq!(land.price_per_fraction, 30000);
+    assert_eq!(land.land_price, 120000);
+    assert_eq!(client.try_execute_proposal(&proposal_id), Err(Ok(GovernanceError::ProposalNotActive)));
+
+    // Appointing a manager and approving a parcel sale
+    let manager = Address::generate(&env);
+    let proposal_id = client.create_proposal(&bob, &land_id, &ProposalAction::AppointManager(manager.clone()));
+    client.vote(&alice, &proposal_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 7 * 24 * 60 * 60);
+    client.execute_proposal(&proposal_id);
+    assert_eq!(client.get_land_manager(&land_id), Some(manager));
+
+    let proposal_id = client.create_proposal(&alice, &land_id, &ProposalAction::SellParcel(500000));
+    client.vote(&alice, &proposal_id, &true);
+    client.vote(&bob, &proposal_id, &true);
+    // The fraction still unsold carries no vote
+    assert_eq!(client.try_vote(&seller, &proposal_id, &true), Err(Ok(GovernanceError::NoVotingPower)));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 7 * 24 * 60 * 60);
+    client.execute_proposal(&proposal_id);
+    let sale = client.get_parcel_sale(&land_id).unwrap();
+    assert_eq!((sale.acquirer, sale.price, sale.proposal_id), (None, 500000, proposal_id));
+}
+
+#[test]
+fn test_governance_quorum_and_transferred_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let carol = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 4);
+
+    assert_eq!(
+        client.try_set_governance_config(&admin, &5_000, &4_000, &100),
+        Err(Ok(GovernanceError::InvalidGovernanceConfig))
+    );
+    assert_eq!(
+        client.try_set_governance_config(&seller, &5_000, &5_000, &100),
+        Err(Ok(GovernanceError::NotAdmin))
+    );
+    client.set_governance_config(&admin, &7_500, &6_000, &100);
+    assert_eq!(
+        client.get_governance_config(),
+        GovernanceConfig { quorum_bps: 7_500, threshold_bps: 6_000, voting_period: 100 }
+    );
+
+    // A fraction votes once, even after it changes hands
+    let acquirer = Address::generate(&env);
+    let action = ProposalAction::ConvertToWhole(acquirer, 400000);
+    let proposal_id = client.create_proposal(&alice, &land_id, &action);
+    client.transfer_fraction(&alice, &land_id, &1, &carol);
+    assert_eq!(client.vote(&alice, &proposal_id, &true), 3);
+    client.transfer_fraction(&alice, &land_id, &2, &carol);
+    assert_eq!(client.vote(&carol, &proposal_id, &false), 1);
+    assert_eq!(client.try_vote(&carol, &proposal_id, &false), Err(Ok(GovernanceError::NoVotingPower)));
+
+    // 3 of 4 in favour clears a 60% threshold with full turnout, and a 75% buyout threshold
+    client.set_buyout_threshold(&admin, &7_500);
+    env.ledger().set_timestamp(100);
+    assert_eq!(client.execute_proposal(&proposal_id), ProposalStatus::Executed);
+    assert!(client.get_parcel_sale(&land_id).is_some());
+
+    // 2 of 4 voting misses a 75% quorum
+    let proposal_id = client.create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(1));
+    client.vote(&carol, &proposal_id, &true);
+    env.ledger().set_timestamp(200);
+    assert_eq!(client.execute_proposal(&proposal_id), ProposalStatus::Defeated);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::ProposalDefeated { proposal_id, land_id, votes_for: 2, votes_against: 0 },
+    );
+    assert_eq!(client.get_land(&land_id).price_per_fraction, 25000);
+
+    // A very long voting period ends at the last representable timestamp
+    client.set_governance_config(&admin, &5_000, &5_000, &u64::MAX);
+    let proposal_id = client.create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(1));
+    assert_eq!(client.get_proposal(&proposal_id).ends_at, u64::MAX);
+
+    client.pause(&admin, &vec![&env, PauseScope::Governance]);
+    assert_eq!(
+        client.try_create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(1)),
+        Err(Ok(GovernanceError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_governance_votes_follow_sold_fractions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 100, 60);
+    token_admin.mint(&bob, &100000);
+    client.request_fractional_land(&bob, &land_id, &30);
+    client.approve_request(&seller, &2);
+
+    // A large holder votes in one bounded call
+    let proposal_id = client.create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(2000));
+    assert_eq!(client.vote(&alice, &proposal_id, &true), 60);
+    assert!(env.cost_estimate().resources().write_entries < 10);
+
+    // The seller's 40 unsold fractions carry no vote until they are sold
+    assert_eq!(client.try_vote(&seller, &proposal_id, &false), Err(Ok(GovernanceError::NoVotingPower)));
+    assert_eq!(
+        client.try_create_proposal(&seller, &land_id, &ProposalAction::ChangePrice(1)),
+        Err(Ok(GovernanceError::NoVotingPower))
+    );
+    client.payment(&bob, &2);
+    assert_eq!(client.vote(&bob, &proposal_id, &false), 30);
+    assert_eq!(client.try_vote(&seller, &proposal_id, &true), Err(Ok(GovernanceError::NoVotingPower)));
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!((proposal.votes_for, proposal.votes_against), (60, 30));
+
+    // Voted fractions that change hands stay voted
+    client.transfer_fraction(&alice, &land_id, &1, &bob);
+    assert_eq!(client.try_vote(&bob, &proposal_id, &true), Err(Ok(GovernanceError::NoVotingPower)));
+
+    // Open proposals per land are capped; ones whose voting ended free their slot
+    for _ in 1..5 {
+        client.create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(2000));
+    }
+    assert_eq!(
+        client.try_create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(2000)),
+        Err(Ok(GovernanceError::TooManyProposals))
+    );
+    env.ledger().set_timestamp(proposal.ends_at);
+    client.create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(2000));
+}
+
+#[test]
+fn test_parcel_sale_needs_buyout_supermajority() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 10, 6);
+    token_admin.mint(&bob, &100000);
+    client.request_fractional_land(&bob, &land_id, &4);
+    client.approve_request(&seller, &2);
+    client.payment(&bob, &2);
+
+    // A majority holder cannot sell everyone out at a price of its choosing
+    let proposal_id = client.create_proposal(&alice, &land_id, &ProposalAction::SellParcel(10));
+    assert_eq!(client.vote(&alice, &proposal_id, &true), 6);
+    env.ledger().set_timestamp(client.get_proposal(&proposal_id).ends_at);
+    assert_eq!(client.execute_proposal(&proposal_id), ProposalStatus::Defeated);
+    assert!(client.get_parcel_sale(&land_id).is_none());
+
+    // Nor can it vote on converting the land to itself; only the other holders decide
+    let action = ProposalAction::ConvertToWhole(alice.clone(), 10);
+    let proposal_id = client.create_proposal(&alice, &land_id, &action);
+    assert_eq!(client.get_proposal(&proposal_id).eligible_fractions, 4);
+    assert_eq!(client.try_vote(&alice, &proposal_id, &true), Err(Ok(GovernanceError::AcquirerCannotVote)));
+    assert_eq!(client.vote(&bob, &proposal_id, &false), 4);
+    env.ledger().set_timestamp(client.get_proposal(&proposal_id).ends_at);
+    assert_eq!(client.execute_proposal(&proposal_id), ProposalStatus::Defeated);
+    assert_eq!(client.try_buyout(&alice, &land_id), Err(Ok(GovernanceError::BuyoutNotAllowed)));
+}
+
+#[test]
+fn test_forced_buyout_by_supermajority_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 3);
+    token_admin.mint(&bob, &100000);
+    client.request_fractional_land(&bob, &land_id, &1);
+    client.approve_request(&seller, &2);
+
+    // 3 of 4 is below the default 90% threshold
+    assert_eq!(client.get_buyout_threshold(), 9_000);
+    assert_eq!(client.try_buyout(&alice, &land_id), Err(Ok(GovernanceError::BuyoutNotAllowed)));
+    assert_eq!(
+        client.try_set_buyout_threshold(&admin, &5_000),
+        Err(Ok(GovernanceError::InvalidGovernanceConfig))
+    );
+    client.set_buyout_threshold(&admin, &7_500);
+    assert_eq!(client.try_buyout(&alice, &land_id), Err(Ok(GovernanceError::FractionsReserved)));
+    client.payment(&bob, &2);
+    assert_eq!(client.try_buyout(&bob, &land_id), Err(Ok(GovernanceError::BuyoutNotAllowed)));
+
+    let alice_balance = token.balance(&alice);
+    assert_eq!(client.buyout(&alice, &land_id), 25000);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::ParcelBoughtOut {
+            land_id,
+            acquirer: alice.clone(),
+            previous_owner: seller.clone(),
+            price_per_fraction: 25000,
+            escrowed: 25000,
+        },
+    );
+    assert_eq!(token.balance(&alice), alice_balance - 25000);
+
+    // The land is whole again and owned by alice
+    let land = client.get_land(&land_id);
+    assert!(!land.is_fractional);
+    assert_eq!((land.total_fractions, land.fractions_sold, land.land_price), (0, 0, 100000));
+    assert_eq!(client.get_land_owner(&land_id), alice);
+    let title = client.get_title_history(&land_id, &1, &1).get(0).unwrap();
+    assert_eq!((title.owner, title.req_id, title.price), (alice.clone(), None, 100000));
+    assert_eq!(client.get_user_fractional_lands(&alice).len(), 0);
+    assert!(client.try_get_fractional_ownership(&land_id, &1).is_err());
+    assert_eq!(client.try_buyout(&alice, &land_id), Err(Ok(GovernanceError::LandNotFractional)));
+
+    // Bob keeps his holding until his compensation is paid out of escrow
+    assert_eq!(client.get_land_fraction_owners(&land_id), vec![&env, bob.clone()]);
+    assert_eq!(client.get_user_fractional_lands(&bob).len(), 1);
+    assert_eq!(client.get_buyout_compensation(&land_id, &bob), 25000);
+    assert_eq!(client.get_buyout_compensation(&land_id, &alice), 0);
+    let settlement = client.get_buyout_settlement(&land_id).unwrap();
+    assert_eq!((settlement.acquirer, settlement.remainder_holder), (alice.clone(), bob.clone()));
+    assert_eq!(
+        client.try_fractionalize_land(&alice, &land_id, &4, &100000, &4),
+        Err(Ok(RegistryError::FundsInEscrow))
+    );
+
+    // Anyone may trigger the payout; it only ever goes to the holder
+    let bob_balance = token.balance(&bob);
+    assert_eq!(client.claim_buyout_compensation(&bob, &land_id), 25000);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::BuyoutCompensationClaimed { land_id, holder: bob.clone(), amount: 25000 },
+    );
+    assert_eq!(token.balance(&bob), bob_balance + 25000);
+    assert_eq!(
+        client.try_claim_buyout_compensation(&bob, &land_id),
+        Err(Ok(GovernanceError::NothingToClaim))
+    );
+    assert_eq!(client.get_land_fraction_owners(&land_id).len(), 0);
+    assert_eq!(client.get_user_fractional_lands(&bob).len(), 0);
+    assert!(client.get_buyout_settlement(&land_id).is_none());
+}
+
+#[test]
+fn test_unsold_fractions_do_not_count_toward_buyout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_buyer(&env, &client, &inspector);
+    add_seller_role(&env, &client, &inspector, &seller);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 1);
+    token_admin.mint(&seller, &100000);
+    client.set_buyout_threshold(&admin, &7_500);
+
+    // The seller still holds 3 of 4 fractions, but none of them were ever issued
+    assert_eq!(client.get_holder_share(&land_id, &seller), (3, 4));
+    assert_eq!(client.try_buyout(&seller, &land_id), Err(Ok(GovernanceError::BuyoutNotAllowed)));
+    assert!(client.get_land(&land_id).is_fractional);
+}
+
+#[test]
+fn test_buyout_settles_holders_lazily() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 100, 90);
+    token_admin.mint(&alice, &20000);
+    client.set_buyout_threshold(&admin, &7_500);
+
+    // Scatter alice's run across ten holders and leave some listings open
+    let mut holders = Vec::new(&env);
+    for fraction_id in (2..=20).step_by(2) {
+        let holder = register_verified_buyer(&env, &client, &inspector);
+        client.transfer_fraction(&alice, &land_id, &fraction_id, &holder);
+        holders.push_back(holder);
+    }
+    for fraction_id in 21..=25 {
+        client.list_fraction_for_sale(&alice, &land_id, &fraction_id, &1500);
+    }
+
+    // The buyout touches a fixed set of records however many holders and runs there are
+    assert_eq!(client.buyout(&alice, &land_id), 20000);
+    assert!(env.cost_estimate().resources().write_entries < 20);
+    assert_eq!(
+        client.try_get_fraction_listing(&land_id, &21).err(),
+        Some(Ok(RegistryError::FractionNotListed))
+    );
+
+    // The seller is paid for the unsold fractions, each holder for theirs
+    assert_eq!(client.get_buyout_compensation(&land_id, &seller), 10000);
+    assert_eq!(client.claim_buyout_compensation(&seller, &land_id), 10000);
+    for holder in holders.iter() {
+        assert_eq!(client.get_buyout_compensation(&land_id, &holder), 1000);
+        assert!(client.get_buyout_settlement(&land_id).is_some());
+        assert_eq!(client.claim_buyout_compensation(&holder, &land_id), 1000);
+    }
+    assert!(client.get_buyout_settlement(&land_id).is_none());
+    assert_eq!(client.get_land_fraction_owners(&land_id).len(), 0);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_buyout_of_approved_parcel_sale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let dave = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 4, 2);
+    token_admin.mint(&dave, &200000);
+    token_admin.mint(&seller, &1000);
+
+    let proposal_id = client.create_proposal(&alice, &land_id, &ProposalAction::SellParcel(200000));
+    let stale_id = client.create_proposal(&alice, &land_id, &ProposalAction::ChangePrice(1));
+    client.vote(&alice, &proposal_id, &true);
+    client.vote(&alice, &stale_id, &true);
+    assert_eq!(client.try_buyout(&dave, &land_id), Err(Ok(GovernanceError::BuyoutNotAllowed)));
+    env.ledger().set_timestamp(7 * 24 * 60 * 60);
+    client.execute_proposal(&proposal_id);
+
+    // Income earned before the buyout can still be claimed afterwards
+    client.deposit_income(&seller, &land_id, &1000);
+
+    // An outside buyer pays the approved price for all four fractions
+    assert_eq!(client.buyout(&dave, &land_id), 200000);
+    assert_eq!(client.get_land_owner(&land_id), dave);
+    assert_eq!(client.get_land(&land_id).land_price, 200000);
+    assert_eq!(client.get_buyout_compensation(&land_id, &alice), 100000);
+    assert_eq!(client.get_buyout_compensation(&land_id, &seller), 100000);
+    assert!(client.get_parcel_sale(&land_id).is_none());
+    assert_eq!(client.claim_income(&alice, &land_id), 500);
+    assert_eq!(client.claim_buyout_compensation(&alice, &land_id), 100000);
+    assert_eq!(client.claim_buyout_compensation(&seller, &land_id), 100000);
+    assert_eq!(client.claim_income(&seller, &land_id), 500);
+    assert_eq!(token.balance(&client.address), 0);
+
+    // Proposals left open on the parcel lapse
+    assert_eq!(client.execute_proposal(&stale_id), ProposalStatus::Defeated);
+}
+
+#[test]
+fn test_fractionalize_whole_land() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    token_admin.mint(&buyer, &100000);
+
+    assert_eq!(
+        client.try_fractionalize_land(&seller, &land_id, &4, &20000, &2),
+        Err(Ok(RegistryError::LandNotVerified))
+    );
+    client.verify_land(&inspector, &land_id);
+    assert_eq!(
+        client.try_fractionalize_land(&buyer, &land_id, &4, &20000, &2),
+        Err(Ok(RegistryError::NotLandOwner))
+    );
+    assert_eq!(
+        client.try_fractionalize_land(&seller, &land_id, &4, &20000, &5),
+        Err(Ok(RegistryError::InvalidFractionCount))
+    );
+    assert_eq!(
+        client.try_approve_fractionalization(&inspector, &land_id),
+        Err(Ok(RegistryError::FractionalizationNotFound))
+    );
+
+    // A whole-land request approved before the conversion cannot be paid after it
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+
+    client.fractionalize_land(&seller, &land_id, &4, &20000, &2);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::FractionalizationRequested {
+            land_id,
+            owner: seller.clone(),
+            total_fractions: 4,
+            total_price: 20000,
+            retained_fractions: 2,
+        },
+    );
+    assert!(!client.get_land(&land_id).is_fractional);
+    assert_eq!(client.get_pending_fractionalization(&land_id).retained_fractions, 2);
+
+    client.approve_fractionalization(&inspector, &land_id);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::LandFractionalized {
+            land_id,
+            inspector: inspector.clone(),
+            owner: seller.clone(),
+            total_fractions: 4,
+            price_per_fraction: 5000,
+        },
+    );
+    assert_eq!(client.try_payment(&buyer, &1), Err(Ok(RegistryError::LandIsFractional)));
+    assert!(client.try_get_pending_fractionalization(&land_id).is_err());
+
+    let land = client.get_land(&land_id);
+    assert!(land.is_fractional);
+    assert_eq!((land.total_fractions, land.fractions_sold), (4, 2));
+    // Retained fractions plus the unsold ones
+    assert_eq!(client.get_holder_share(&land_id, &seller), (4, 4));
+    assert_eq!(client.get_land_fraction_owners(&land_id), vec![&env, seller.clone()]);
+    assert_eq!(client.get_fractional_ownership(&land_id, &2).owner, seller);
+    assert_eq!(client.get_available_fractions(&land_id), 2);
+
+    // The remaining fractions sell as usual
+    client.request_fractional_land(&buyer, &land_id, &2);
+    client.approve_request(&seller, &2);
+    client.payment(&buyer, &2);
+    assert_eq!(client.get_request(&2).fraction_id, Some(3));
+    assert_eq!(client.get_holder_share(&land_id, &buyer), (2, 4));
+    assert_eq!(
+        client.try_fractionalize_land(&seller, &land_id, &4, &20000, &0),
+        Err(Ok(RegistryError::LandIsFractional))
+    );
+}
+
+#[test]
+fn test_reject_fractionalization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+
+    let outsider = Address::generate(&env);
+    client.add_inspector(
+        &client.get_admin(),
+        &outsider,
+        &String::from_str(&env, "Inspector 2"),
+        &45,
+        &String::from_str(&env, "Tehsil Manager"),
+        &state_wide(&env, "Gujarat")
+    );
+
+    client.fractionalize_land(&seller, &land_id, &10, &20000, &0);
+    assert_eq!(
+        client.try_approve_fractionalization(&outsider, &land_id),
+        Err(Ok(RegistryError::OutsideJurisdiction))
+    );
+    client.reject_fractionalization(&inspector, &land_id);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::FractionalizationRejected { land_id, inspector: inspector.clone(), owner: seller.clone() },
+    );
+    assert!(!client.get_land(&land_id).is_fractional);
+    assert_eq!(
+        client.try_approve_fractionalization(&inspector, &land_id),
+        Err(Ok(RegistryError::FractionalizationNotFound))
+    );
+}
+
+#[test]
+fn test_last_fraction_absorbs_price_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let alice = register_verified_buyer(&env, &client, &inspector);
+    let bob = register_verified_buyer(&env, &client, &inspector);
+
+    // 100000 does not divide into 3 fractions
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &alice, 3, 2);
+    let land = client.get_land(&land_id);
+    assert_eq!((land.land_price, land.price_per_fraction), (100000, 33333));
+    assert_eq!(token.balance(&seller), 66666);
+    assert_eq!(client.get_holder_share(&land_id, &seller), (1, 3));
+    assert_eq!(client.get_land_fraction_owners(&land_id), vec![&env, seller.clone(), alice.clone()]);
+
+    token_admin.mint(&bob, &100000);
+    client.request_fractional_land(&bob, &land_id, &1);
+    client.approve_request(&seller, &2);
+    client.payment(&bob, &2);
+    assert_eq!(token.balance(&bob), 100000 - 33334);
+    assert_eq!(token.balance(&seller), 100000);
+
+    // Once everything is sold the seller drops out of the holders
+    assert_eq!(client.get_holder_share(&land_id, &seller), (0, 3));
+    assert_eq!(client.get_land_fraction_owners(&land_id), vec![&env, alice.clone(), bob.clone()]);
+}
+
+#[test]
+fn test_migrate_v5_credits_unsold_fractions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = buy_fractions(&env, &client, &inspector, &token_admin, &seller, &buyer, 4, 1);
+
+    // v5 left unsold fractions out of the holder indexes
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &5u32);
+        env.storage().persistent().remove(&DataKey::HolderFractions(land_id, seller.clone()));
+        env.storage().persistent().set(&DataKey::LandFractionOwners(land_id), &vec![&env, buyer.clone()]);
+        env.storage().persistent().set(&DataKey::UserFractionalLands(seller.clone()), &Vec::<u32>::new(&env));
+    });
+
+    assert_eq!(client.migrate(&admin, &500), SCHEMA_VERSION);
+    assert_eq!(client.get_holder_share(&land_id, &seller), (3, 4));
+    assert_eq!(client.get_land_fraction_owners(&land_id), vec![&env, buyer.clone(), seller.clone()]);
+    assert_eq!(client.get_user_fractional_lands(&seller), vec![&env, land_id]);
+}
+
+#[test]
+fn test_buyer_gains_seller_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let other_land = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &other_land);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    assert_eq!(client.get_party(&buyer).role, PartyRole::Buyer);
+    assert_eq!(client.try_get_seller(&buyer).err(), Some(Ok(RegistryError::SellerNotFound)));
+
+    client.register_seller(
+        &buyer,
+        &String::from_str(&env, "Buyer"),
+        &25,
+        &String::from_str(&env, "Mumbai"),
+        &String::from_str(&env, "Maharashtra"),
+        &String::from_str(&env, "aadhar"),
+        &String::from_str(&env, "pan"),
+        &String::from_str(&env, "one"),
+        &String::from_str(&env, "QmSellerHash")
+    );
+    let party = client.get_party(&buyer);
+    assert_eq!(party.role, PartyRole::Both);
+    assert_eq!(party.email, String::from_str(&env, "buyer@email.com"));
+    assert_eq!(party.lands_owned, String::from_str(&env, "one"));
+    assert_eq!(client.get_sellers_count(), 2);
+    assert_eq!(client.get_seller_list(), vec![&env, seller.clone(), buyer.clone()]);
+
+    // The new details need verifying before the party can act in either role
+    assert!(!party.verified);
+    assert_eq!(client.try_request_land(&buyer, &other_land), Err(Ok(RegistryError::BuyerNotVerified)));
+    client.verify_seller(&inspector, &buyer);
+    let land_id = add_small_fractional_land(&env, &client, &buyer, 4);
+    assert_eq!(client.get_land_owner(&land_id), buyer);
+    client.request_land(&buyer, &other_land);
+
+    assert_eq!(
+        client.try_register_buyer(
+            &buyer,
+            &String::from_str(&env, "Buyer"),
+            &25,
+            &String::from_str(&env, "Mumbai"),
+            &String::from_str(&env, "Maharashtra"),
+            &String::from_str(&env, "aadhar"),
+            &String::from_str(&env, "pan"),
+            &String::from_str(&env, "QmHash"),
+            &String::from_str(&env, "buyer@email.com")
+        ),
+        Err(Ok(RegistryError::AlreadyRegistered))
+    );
+}
+
+#[test]
+fn test_migrate_v6_buyer_and_seller_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+
+    // v6 kept a Seller or Buyer record plus a RegisteredAddress marker per address
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &6u32);
+        let old_seller = migration::SellerV6 {
+            id: seller.clone(),
+            name: String::from_str(&env, "Seller"),
+            age: 30,
+            city: String::from_str(&env, "Akola"),
+            state: String::from_str(&env, "Maharashtra"),
+            aadhar_number: String::from_str(&env, "abc"),
+            pan_number: String::from_str(&env, "xyz"),
+            lands_owned: String::from_str(&env, "many"),
+            document: String::from_str(&env, "QmHash"),
+            verified: true,
+            rejected: false,
+        };
+        let old_buyer = migration::BuyerV6 {
+            id: buyer.clone(),
+            name: String::from_str(&env, "Buyer"),
+            age: 25,
+            city: String::from_str(&env, "Mumbai"),
+            state: String::from_str(&env, "Maharashtra"),
+            aadhar_number: String::from_str(&env, "aadhar"),
+            pan_number: String::from_str(&env, "pan"),
+            document: String::from_str(&env, "QmHash"),
+            email: String::from_str(&env, "buyer@email.com"),
+            verified: false,
+            rejected: true,
+        };
+        env.storage().persistent().set(&migration::DataKeyV6::Seller(seller.clone()), &old_seller);
+        env.storage().persistent().set(&migration::DataKeyV6::Buyer(buyer.clone()), &old_buyer);
+        for address in [&seller, &buyer] {
+            env.storage().persistent().remove(&DataKey::Party(address.clone()));
+            env.storage().persistent().set(&migration::DataKeyV6::RegisteredAddress(address.clone()), &true);
+        }
+    });
+
+    assert_eq!(client.migrate(&admin, &500), SCHEMA_VERSION);
+    let party = client.get_seller(&seller);
+    assert_eq!((party.role, party.verified), (PartyRole::Seller, true));
+    assert_eq!(party.lands_owned, String::from_str(&env, "many"));
+    let party = client.get_buyer(&buyer);
+    assert_eq!((party.role, party.verified, party.rejected), (PartyRole::Buyer, false, true));
+    assert_eq!(party.email, String::from_str(&env, "buyer@email.com"));
+
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&migration::DataKeyV6::Seller(seller.clone())));
+        assert!(!env.storage().persistent().has(&migration::DataKeyV6::Buyer(buyer.clone())));
+        assert!(!env.storage().persistent().has(&migration::DataKeyV6::RegisteredAddress(seller.clone())));
+    });
+}
+
+#[test]
+fn test_resale_by_new_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let next_buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    assert_eq!(client.get_land_listing(&land_id).seller, seller);
+    token_admin.mint(&buyer, &20000);
+    token_admin.mint(&next_buyer, &30000);
+
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+    client.transfer_ownership(&inspector, &1);
+    assert!(client.try_get_land_listing(&land_id).is_err());
+
+    assert_eq!(
+        client.try_list_land_for_sale(&buyer, &land_id, &30000),
+        Err(Ok(RegistryError::SellerNotFound))
+    );
+    add_seller_role(&env, &client, &inspector, &buyer);
+    assert_eq!(
+        client.try_list_land_for_sale(&seller, &land_id, &30000),
+        Err(Ok(RegistryError::NotLandOwner))
+    );
+    assert_eq!(client.try_list_land_for_sale(&buyer, &land_id, &0), Err(Ok(RegistryError::InvalidPrice)));
+
+    client.list_land_for_sale(&buyer, &land_id, &30000);
+    assert_last_event(
+        &env,
+        &client.address,
+        events::LandListed { land_id, seller: buyer.clone(), price: 30000 },
+    );
+    assert_eq!(client.get_land(&land_id).land_price, 30000);
+
+    // The resale settles on the same land_id at the new price
+    client.request_land(&next_buyer, &land_id);
+    client.approve_request(&buyer, &2);
+    assert_eq!(client.get_request(&2).price, 30000);
+
+    // Relisting after approval does not reprice the agreed sale
+    client.list_land_for_sale(&buyer, &land_id, &90000);
+    client.payment(&next_buyer, &2);
+    assert_eq!(client.get_escrow(&2).amount, 30000);
+    client.transfer_ownership(&inspector, &2);
+    assert_eq!(client.get_land_owner(&land_id), next_buyer);
+    assert_eq!(token.balance(&buyer), 30000);
+    assert_eq!(client.get_lands_count(), 1);
+}
+
+#[test]
+fn test_delist_land() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    client.request_land(&buyer, &land_id);
+
+    assert_eq!(client.try_delist_land(&buyer, &land_id), Err(Ok(RegistryError::NotLandOwner)));
+    client.delist_land(&seller, &land_id);
+    assert_last_event(&env, &client.address, events::LandDelisted { land_id, seller: seller.clone() });
+
+    // Open requests stall until the land is listed again
+    assert_eq!(client.try_approve_request(&seller, &1), Err(Ok(RegistryError::LandNotForSale)));
+    assert_eq!(client.try_request_land(&buyer, &land_id), Err(Ok(RegistryError::LandNotForSale)));
+    assert_eq!(client.try_delist_land(&seller, &land_id), Err(Ok(RegistryError::LandNotForSale)));
+
+    client.list_land_for_sale(&seller, &land_id, &20000);
+    client.approve_request(&seller, &1);
+}
+
+#[test]
+fn test_fractionalization_waits_for_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, token, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let other_buyer = register_verified_buyer(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&buyer, &20000);
+    token_admin.mint(&other_buyer, &20000);
+
+    client.request_land(&buyer, &land_id);
+    client.request_land(&other_buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.approve_request(&seller, &2);
+    client.fractionalize_land(&seller, &land_id, &4, &20000, &2);
+
+    // Once a payment is in escrow the land keeps its form until it is sold or refunded
+    client.payment(&buyer, &1);
+    assert_eq!(client.get_land_listing(&land_id).pending_sale, Some(1));
+    assert_eq!(
+        client.try_approve_fractionalization(&inspector, &land_id),
+        Err(Ok(RegistryError::FundsInEscrow))
+    );
+    assert_eq!(
+        client.try_fractionalize_land(&seller, &land_id, &4, &20000, &2),
+        Err(Ok(RegistryError::FundsInEscrow))
+    );
+    assert_eq!(client.try_delist_land(&seller, &land_id), Err(Ok(RegistryError::FundsInEscrow)));
+    assert_eq!(client.try_payment(&other_buyer, &2), Err(Ok(RegistryError::FundsInEscrow)));
+    client.list_land_for_sale(&seller, &land_id, &25000);
+    assert_eq!(client.get_land_listing(&land_id).pending_sale, Some(1));
+
+    env.ledger().set_timestamp(client.get_escrow(&1).deadline);
+    client.refund_escrow(&buyer, &1);
+    assert_eq!(token.balance(&buyer), 20000);
+    assert!(client.get_land_listing(&land_id).pending_sale.is_none());
+    client.approve_fractionalization(&inspector, &land_id);
+    assert!(client.get_land(&land_id).is_fractional);
+}
+
+#[test]
+fn test_migrate_v7_lists_whole_lands() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+    let fractional_land = add_small_fractional_land(&env, &client, &seller, 4);
+
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    client.verify_land(&inspector, &land_id);
+    token_admin.mint(&buyer, &20000);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+
+    // v7 had no listings; every whole land could be requested
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &7u32);
+        env.storage().persistent().remove(&DataKey::LandListing(land_id));
+    });
+
+    // A sale already paid for is recorded on the new listing
+    assert_eq!(client.migrate(&admin, &500), SCHEMA_VERSION);
+    let listing = client.get_land_listing(&land_id);
+    assert_eq!((listing.seller, listing.price, listing.pending_sale), (seller, 20000, Some(1)));
+    assert!(client.try_get_land_listing(&fractional_land).is_err());
+}
+
+#[test]
+fn test_title_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, token_admin) = setup_contract(&env);
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let buyer = register_verified_buyer(&env, &client, &inspector);
+    let next_buyer = register_verified_buyer(&env, &client, &inspector);
+    token_admin.mint(&buyer, &20000);
+    token_admin.mint(&next_buyer, &30000);
+
+    env.ledger().set_timestamp(100);
+    let land_id = add_sample_land(&env, &client, &seller);
+    client.verify_land(&inspector, &land_id);
+    assert_eq!(
+        client.get_title_history(&land_id, &0, &10),
+        vec![&env, TitleEntry { owner: seller.clone(), from_ts: 100, to_ts: None, req_id: None, price: 20000, inspector: None }]
+    );
+
+    env.ledger().set_timestamp(200);
+    client.request_land(&buyer, &land_id);
+    client.approve_request(&seller, &1);
+    client.payment(&buyer, &1);
+    client.transfer_ownership(&inspector, &1);
+
+    env.ledger().set_timestamp(300);
+    add_seller_role(&env, &client, &inspector, &buyer);
+    client.list_land_for_sale(&buyer, &land_id, &30000);
+    client.request_land(&next_buyer, &land_id);
+    client.approve_request(&buyer, &2);
+    client.payment(&next_buyer, &2);
+    client.transfer_ownership(&inspector, &2);
+
+    assert_eq!(client.get_title_history_len(&land_id), 3);
+    assert_eq!(
+        client.get_title_history(&land_id, &0, &10),
+        vec![
+            &env,
+            TitleEntry { owner: seller.clone(), from_ts: 100, to_ts: Some(200), req_id: None, price: 20000, inspector: None },
+            TitleEntry {
+                owner: buyer.clone(),
+                from_ts: 200,
+                to_ts: Some(300),
+                req_id: Some(1),
+                price: 20000,
+                inspector: Some(inspector.clone()),
+            },
+            TitleEntry {
+                owner: next_buyer.clone(),
+                from_ts: 300,
+                to_ts: None,
+                req_id: Some(2),
+                price: 30000,
+                inspector: Some(inspector.clone()),
+            },
+        ]
+    );
+
+    // Pages never run past the end of the chain
+    let page = client.get_title_history(&land_id, &1, &1);
+    assert_eq!((page.len(), page.get(0).unwrap().owner), (1, buyer.clone()));
+    assert_eq!(client.get_title_history(&land_id, &2, &5).len(), 1);
+    assert_eq!(client.get_title_history(&land_id, &3, &5).len(), 0);
+    assert_eq!(client.try_get_title_history(&99, &0, &10), Err(Ok(RegistryError::LandNotFound)));
+
+    // Each entry is its own record, so old ones are extended a page at a time
+    client.bump_title_history(&land_id, &0, &10);
+    assert_eq!(client.try_bump_title_history(&99, &0, &10), Err(Ok(RegistryError::LandNotFound)));
+    env.as_contract(&client.address, || {
+        let entry: TitleEntry = env.storage().persistent().get(&DataKey::TitleEntry(land_id, 0)).unwrap();
+        assert_eq!(entry.to_ts, Some(200));
+    });
+}
+
+#[test]
+fn test_migrate_v8_starts_title_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, inspector, _, _) = setup_contract(&env);
+    let admin = client.get_admin();
+    let seller = register_verified_seller(&env, &client, &inspector);
+    let land_id = add_sample_land(&env, &client, &seller);
+
+    // v8 kept no history
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &8u32);
+        env.storage().persistent().remove(&DataKey::TitleEntry(land_id, 0));
+        env.storage().persistent().remove(&DataKey::TitleLength(land_id));
+    });
+
+    env.ledger().set_timestamp(500);
+    assert_eq!(client.migrate(&admin, &500), SCHEMA_VERSION);
+    assert_eq!(
+        client.get_title_history(&land_id, &0, &10),
+        vec![&env, TitleEntry { owner: seller, from_ts: 500, to_ts: None, req_id: None, price: 20000, inspector: None }]
+    );
+}